@@ -0,0 +1,134 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering::Relaxed},
+};
+
+use serde::Serialize;
+
+//--------------------------------------------------------------------------------
+// allocator
+//--------------------------------------------------------------------------------
+
+// the system allocator, counting as it goes. it has to be installed by the binary:
+//     #[global_allocator]
+//     static ALLOC: CountingAlloc = CountingAlloc;
+// without it, every count stays at 0
+pub struct CountingAlloc;
+
+static NB_ALLOCS: AtomicU64 = AtomicU64::new(0);
+static NB_BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0); // live bytes
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+fn on_alloc(size: usize) {
+    NB_ALLOCS.fetch_add(1, Relaxed);
+    NB_BYTES.fetch_add(size as u64, Relaxed);
+    let current = CURRENT.fetch_add(size as u64, Relaxed) + size as u64;
+    PEAK.fetch_max(current, Relaxed);
+}
+
+fn on_dealloc(size: usize) {
+    CURRENT.fetch_sub(size as u64, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            on_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        on_dealloc(layout.size());
+    }
+
+    // a growing vec: one more allocation of the new size, the old block is given back
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            on_dealloc(layout.size());
+            on_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+//--------------------------------------------------------------------------------
+// accounting
+//--------------------------------------------------------------------------------
+
+// what a piece of code allocated. the counters are global: other threads allocating at the
+// same time are counted too, so measure on a quiet process (bench runs one part at a time)
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+    // the most heap bytes live at once, above what was live before. not the resident memory: pages
+    // the allocator keeps, the stack and the binary are not in it
+    pub peak_heap_bytes: u64,
+}
+
+impl Allocs {
+    pub fn pp(&self) -> String {
+        format!(
+            "{} allocs, {}, peak heap {}",
+            self.count,
+            crate::utils::fmt_bytes(self.bytes),
+            crate::utils::fmt_bytes(self.peak_heap_bytes)
+        )
+    }
+}
+
+pub fn count_allocs<R>(f: impl FnOnce() -> R) -> (R, Allocs) {
+    let base = CURRENT.load(Relaxed);
+    PEAK.store(base, Relaxed);
+    let (nb_allocs, nb_bytes) = (NB_ALLOCS.load(Relaxed), NB_BYTES.load(Relaxed));
+    let res = f();
+    let allocs = Allocs {
+        count: NB_ALLOCS.load(Relaxed) - nb_allocs,
+        bytes: NB_BYTES.load(Relaxed) - nb_bytes,
+        peak_heap_bytes: PEAK.load(Relaxed).saturating_sub(base),
+    };
+    (res, allocs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    #[test]
+    fn test() {
+        // the other tests allocate in parallel: only lower bounds are certain
+        let (_, allocs) = count_allocs(|| {
+            let v: Vec<u64> = Vec::with_capacity(1000);
+            let w: Vec<u64> = Vec::with_capacity(1000);
+            (v.capacity(), w.capacity())
+        });
+        assert!(allocs.count >= 2);
+        assert!(allocs.bytes >= 16000);
+        assert!(allocs.peak_heap_bytes >= 16000);
+
+        // freed as soon as made: the peak is one vec, not the sum
+        let (_, allocs) = count_allocs(|| {
+            for _ in 0..10 {
+                drop(Vec::<u8>::with_capacity(100_000));
+            }
+        });
+        assert!(allocs.count >= 10 && allocs.bytes >= 1_000_000);
+        assert!(allocs.peak_heap_bytes >= 100_000);
+    }
+}
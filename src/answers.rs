@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, time::Duration};
+
+use crate::utils::fmt_duration;
+
+use serde::Deserialize;
+
+// one manifest per year
+pub fn answers_file(year: u32) -> String {
+    format!("answers/{}.toml", year)
+}
+
+pub fn manifest_header(year: u32) -> String {
+    format!(
+        "# Expected answers of {}, by day and input file.\n\
+         # `cargo run -- verify` checks all of them, `cargo run -- run` prints PASS/FAIL/UNKNOWN next to each result.\n",
+        year
+    )
+}
+
+//--------------------------------------------------------------------------------
+// status
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail(String), // the expected answer
+    Unknown,
+    Error(String),     // could not even get an answer
+    Timeout(Duration), // gave up after that long
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Status::Unknown => write!(f, "UNKNOWN"),
+            Status::Error(err) => write!(f, "ERROR ({})", err),
+            Status::Timeout(timeout) => write!(f, "TIMEOUT (after {})", fmt_duration(*timeout)),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------
+// manifest
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub p1: Option<String>,
+    pub p2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.p1.as_deref(),
+            _ => self.p2.as_deref(),
+        }
+    }
+}
+
+// day ("day01") -> input file -> expected answers
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Expected>>);
+
+fn day_key(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+impl Answers {
+    pub fn from_str(content: &str) -> Result<Answers, String> {
+        toml::from_str(content).map_err(|err| err.to_string())
+    }
+
+    // a missing manifest is not an error, everything is just unknown
+    pub fn load(file: &str) -> Result<Answers, String> {
+        if !Path::new(file).exists() {
+            return Ok(Answers::default());
+        }
+        let content = fs::read_to_string(file).map_err(|err| format!("cannot read {}: {}", file, err))?;
+        Answers::from_str(&content).map_err(|err| format!("{}: {}", file, err))
+    }
+
+    pub fn expected(&self, day: u32, file: &str, part: u8) -> Option<&str> {
+        self.0.get(&day_key(day))?.get(file)?.part(part)
+    }
+
+    pub fn check(&self, day: u32, file: &str, part: u8, answer: &str) -> Status {
+        match self.expected(day, file, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail(expected.to_string()),
+            None => Status::Unknown,
+        }
+    }
+
+    pub fn has(&self, day: u32, file: &str) -> bool {
+        self.0.get(&day_key(day)).is_some_and(|files| files.contains_key(file))
+    }
+
+    // all the (file, expected) we know for a day, samples first (the real input is usually the slow one)
+    pub fn files(&self, day: u32) -> Vec<(&str, &Expected)> {
+        let mut files = match self.0.get(&day_key(day)) {
+            Some(files) => files.iter().map(|(file, expected)| (file.as_str(), expected)).collect(),
+            None => vec![],
+        };
+        files.sort_by_key(|(file, _)| file.contains("input"));
+        files
+    }
+}
+
+// add an entry to the manifest without touching the rest of the file (comments, order...).
+// within a day, samples go before the real input
+pub fn add_entry(content: &str, day: u32, file: &str, expected: &Expected) -> String {
+    let answers = [("p1", &expected.p1), ("p2", &expected.p2)]
+        .into_iter()
+        .filter_map(|(p, answer)| {
+            answer
+                .as_ref()
+                .map(|a| format!("{} = {}", p, toml::Value::from(a.as_str())))
+        })
+        .collect::<Vec<_>>();
+    let entry = match answers.is_empty() {
+        true => format!("{} = {{}}", toml::Value::from(file)),
+        false => format!("{} = {{ {} }}", toml::Value::from(file), answers.join(", ")),
+    };
+    let header = format!("[{}]", day_key(day));
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    match lines.iter().position(|line| line.trim() == header) {
+        Some(start) => {
+            let end = (start + 1..lines.len())
+                .find(|&i| lines[i].trim_start().starts_with('['))
+                .unwrap_or(lines.len());
+            let pos = (start + 1..end)
+                .find(|&i| lines[i].contains("_input"))
+                .or((start + 1..end)
+                    .rev()
+                    .find(|&i| !lines[i].trim().is_empty())
+                    .map(|i| i + 1))
+                .unwrap_or(start + 1);
+            lines.insert(pos, &entry);
+        }
+        None => {
+            // a new section, in day order
+            let next = lines
+                .iter()
+                .position(|line| line.starts_with("[day") && line.trim() > header.as_str())
+                .unwrap_or(lines.len());
+            let mut section = vec![header.as_str(), entry.as_str(), ""];
+            if next == lines.len() && lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                section.rotate_right(1);
+            }
+            lines.splice(next..next, section);
+        }
+    }
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let answers = Answers::from_str(
+            r#"
+            [day01]
+            "data/01_sample.txt" = { p1 = "11", p2 = "31" }
+            "data/01_input.txt" = { p2 = "42" }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(answers.check(1, "data/01_sample.txt", 1, "11"), Status::Pass);
+        assert_eq!(
+            answers.check(1, "data/01_sample.txt", 2, "30"),
+            Status::Fail("31".to_string())
+        );
+        assert_eq!(answers.check(1, "data/01_input.txt", 1, "11"), Status::Unknown);
+        assert_eq!(answers.check(2, "data/02_input.txt", 1, "11"), Status::Unknown);
+        assert_eq!(answers.files(1).len(), 2);
+
+        assert!(Answers::from_str("[day01]\n\"f\" = { p3 = \"1\" }").is_err());
+
+        // new entries keep the layout: samples before the input, sections in day order
+        let content =
+            "# answers\n\n[day01]\n\"data/01_input.txt\" = { p1 = \"1\" }\n\n[day03]\n\"x\" = { p1 = \"2\" }\n";
+        let expected = Expected {
+            p1: Some(String::from("11")),
+            p2: None,
+        };
+        let content = add_entry(content, 1, "data/01_sample.txt", &expected);
+        let content = add_entry(&content, 2, "data/02_sample.txt", &expected);
+        let content = add_entry(&content, 4, "data/04_sample.txt", &expected);
+        assert_eq!(
+            content,
+            "# answers\n\n[day01]\n\"data/01_sample.txt\" = { p1 = \"11\" }\n\"data/01_input.txt\" = { p1 = \"1\" }\n\n\
+             [day02]\n\"data/02_sample.txt\" = { p1 = \"11\" }\n\n[day03]\n\"x\" = { p1 = \"2\" }\n\n\
+             [day04]\n\"data/04_sample.txt\" = { p1 = \"11\" }\n"
+        );
+        let answers = Answers::from_str(&content).unwrap();
+        assert!(answers.has(2, "data/02_sample.txt"));
+        // the real manifest must always parse
+        assert!(Answers::load(&answers_file(2024)).is_ok());
+        assert!(Answers::from_str(&manifest_header(2025)).is_ok());
+    }
+}
@@ -0,0 +1,160 @@
+use std::{fs, time::Duration};
+
+use serde::Serialize;
+
+use crate::alloc::{count_allocs, Allocs};
+use crate::runner::Job;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// stats
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn from_durations(durations: &[Duration]) -> Stats {
+        let mut ns = durations.iter().map(|d| d.as_nanos() as u64).collect::<Vec<_>>();
+        ns.sort();
+        let n = ns.len();
+        // nearest-rank percentile
+        let p95_idx = ((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1;
+        Stats {
+            runs: n,
+            min_ns: ns[0],
+            median_ns: ns[n / 2],
+            p95_ns: ns[p95_idx],
+        }
+    }
+
+    pub fn pp(&self) -> String {
+        format!(
+            "min {} / med {} / p95 {}",
+            fmt_duration(Duration::from_nanos(self.min_ns)),
+            fmt_duration(Duration::from_nanos(self.median_ns)),
+            fmt_duration(Duration::from_nanos(self.p95_ns))
+        )
+    }
+}
+
+// call f `warmup` times without measuring, then `runs` times with time_it
+pub fn measure<R>(warmup: usize, runs: usize, mut f: impl FnMut() -> R) -> (R, Stats) {
+    for _ in 0..warmup {
+        f();
+    }
+    let mut durations = vec![];
+    let mut res = None;
+    for _ in 0..runs.max(1) {
+        let (r, duration) = time_it(&mut f);
+        durations.push(duration);
+        res = Some(r);
+    }
+    (res.unwrap(), Stats::from_durations(&durations))
+}
+
+//--------------------------------------------------------------------------------
+// bench
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchResult {
+    pub day: u32,
+    pub part: u8,
+    pub file: String,
+    pub answer: String,
+    pub read: Stats,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub parse_allocs: Allocs,
+    pub solve_allocs: Allocs,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub year: u32,
+    pub commit: Option<String>,
+    pub profile: &'static str,
+    pub timestamp: u64,
+    pub warmup: usize,
+    pub runs: usize,
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchReport {
+    pub fn write(&self, file: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|err| err.to_string())?;
+        fs::write(file, json + "\n").map_err(|err| format!("cannot write {}: {}", file, err))
+    }
+}
+
+// read, parse and solve are measured separately, each with its own warm-up (the answer is
+// formatted outside the timing). the allocations of parse and solve are counted on one more run
+pub fn bench_jobs(day: &Day, jobs: &[Job], warmup: usize, runs: usize) -> Vec<BenchResult> {
+    pp_day(&day.name());
+    let mut results = vec![];
+    for job in jobs {
+        let (content, read) = measure(warmup, runs, || read_input(&job.file));
+        let (solver, parse) = measure(warmup, runs, || (day.parse)(&content));
+        let (_, parse_allocs) = count_allocs(|| (day.parse)(&content));
+        let solver = match solver {
+            Ok(solver) => solver,
+            Err(err) => {
+                eprint!("{}", err.locate(&job.file, &content).pp(&content));
+                continue;
+            }
+        };
+        for (part, params) in &job.runs {
+            let (answer, solve) = measure(warmup, runs, || solver.solve(*part, params));
+            let answer = answer.to_string();
+            let (_, solve_allocs) = count_allocs(|| solver.solve(*part, params));
+            println!(
+                "p{} : {} -> {}\n    read {}, parse {}, solve {}\n    parse: {}\n    solve: {}",
+                part,
+                job.file,
+                answer,
+                fmt_duration(Duration::from_nanos(read.median_ns)),
+                fmt_duration(Duration::from_nanos(parse.median_ns)),
+                solve.pp(),
+                parse_allocs.pp(),
+                solve_allocs.pp()
+            );
+            results.push(BenchResult {
+                day: day.day,
+                part: *part,
+                file: job.file.clone(),
+                answer,
+                read: read.clone(),
+                parse: parse.clone(),
+                solve,
+                parse_allocs: parse_allocs.clone(),
+                solve_allocs,
+            });
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let durations = (1..=20).rev().map(Duration::from_millis).collect::<Vec<_>>();
+        let stats = Stats::from_durations(&durations);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min_ns, 1_000_000);
+        assert_eq!(stats.median_ns, 11_000_000);
+        assert_eq!(stats.p95_ns, 19_000_000);
+
+        let stats = Stats::from_durations(&[Duration::from_nanos(5)]);
+        assert_eq!((stats.min_ns, stats.median_ns, stats.p95_ns), (5, 5, 5));
+    }
+}
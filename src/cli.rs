@@ -0,0 +1,289 @@
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+
+use aoc24::runner::Format;
+
+//--------------------------------------------------------------------------------
+// args
+//--------------------------------------------------------------------------------
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc24", about = "Advent of code runner")]
+pub struct Cli {
+    /// Year of the puzzles (default: the latest one solved)
+    #[arg(short, long, global = true)]
+    pub year: Option<u32>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run one or more days
+    Run {
+        /// Days to run: `17`, `1,3,5`, `10..15`, `10..=15` or `all`
+        #[arg(value_parser = parse_days)]
+        days: Days,
+
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Use this input file instead of the day's default ones
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Run on every data/YEAR/NN_*.txt file of the day instead of the declared ones
+        #[arg(long, conflicts_with = "input")]
+        all_inputs: bool,
+
+        /// Override a day param, e.g. `--param take=12` (repeatable)
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+
+        /// Give up on a part after that many seconds and report it as TIMEOUT
+        #[arg(long, value_name = "SECS", value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+
+        /// Output format: `text`, or `json` for one record per part and line
+        #[arg(long, default_value = "text", value_parser = parse_format)]
+        format: Format,
+    },
+
+    /// Run every day on a pool of threads, then print a summary table
+    All {
+        /// Number of threads (default: one per cpu)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Give up on a part after that many seconds and report it as TIMEOUT
+        #[arg(long, value_name = "SECS", value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+
+        /// Output format: `text`, or `json` for one record per part and line
+        #[arg(long, default_value = "text", value_parser = parse_format)]
+        format: Format,
+    },
+
+    /// Rerun a day on all its inputs each time its source, its data files or the answers change
+    Watch {
+        /// Day to watch
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Build and run in release mode
+        #[arg(long)]
+        release: bool,
+
+        /// How often to look for changes, in ms
+        #[arg(long, default_value_t = 500)]
+        interval_ms: u64,
+    },
+
+    /// Benchmark read, parse and solve separately, and write the stats to a JSON file
+    Bench {
+        /// Days to benchmark: `17`, `1,3,5`, `10..15`, `10..=15` or `all`
+        #[arg(value_parser = parse_days)]
+        days: Days,
+
+        /// Only benchmark this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Number of measured runs
+        #[arg(short = 'n', long, default_value_t = 10)]
+        runs: usize,
+
+        /// Number of runs before measuring
+        #[arg(short, long, default_value_t = 2)]
+        warmup: usize,
+
+        /// Where to write the results
+        #[arg(short, long, default_value = "bench.json")]
+        output: String,
+    },
+
+    /// Check every answer of the manifest, exit with 1 if any of them changed
+    Verify {
+        /// Days to verify (default: all)
+        #[arg(value_parser = parse_days)]
+        days: Option<Days>,
+
+        /// Give up on a part after that many seconds and report it as TIMEOUT
+        #[arg(long, value_name = "SECS", value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+
+        /// Output format: `text`, or `json` for one record per part and line
+        #[arg(long, default_value = "text", value_parser = parse_format)]
+        format: Format,
+    },
+
+    /// Compare the latest timing of each part to its previous best, exit with 1 if any got slower
+    Report {
+        /// Allowed slowdown, in %
+        #[arg(short, long, default_value_t = 20.0)]
+        threshold: f64,
+
+        /// Ignore slowdowns smaller than this, in ms (small timings are mostly noise)
+        #[arg(long, default_value_t = 1)]
+        min_diff_ms: u64,
+    },
+
+    /// Extract the examples of a saved puzzle page into data/YEAR/NN_sample*.txt, and their answers into the manifest
+    Samples {
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// The saved puzzle page (default: data/YEAR/NN_puzzle.html, see `fetch`)
+        #[arg(long)]
+        html: Option<String>,
+    },
+
+    /// Create a new day of --year: its module, registered in its year, empty input files and answer stubs
+    New {
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Title of the puzzle
+        #[arg(short, long)]
+        title: String,
+    },
+
+    /// Download the input and the puzzle page of some days into data/YEAR/ (existing files are never downloaded again)
+    Fetch {
+        /// Days to fetch: `17`, `1,3,5`, `10..15`, `10..=15` or `all`
+        #[arg(value_parser = parse_days)]
+        days: Days,
+
+        /// Where to download from
+        #[arg(long, env = "AOC_BASE_URL", default_value = aoc24::fetch::BASE_URL)]
+        base_url: String,
+
+        /// Session cookie of the site
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+    },
+
+    /// Regenerate the tiles of every year (.aoc_tiles/tiles/YEAR/NN.svg) and the star counts of the README
+    Tiles,
+
+    /// Solve a part on its real input and send the answer (known wrong answers and cool-downs are checked first)
+    Submit {
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Where to submit to
+        #[arg(long, env = "AOC_BASE_URL", default_value = aoc24::fetch::BASE_URL)]
+        base_url: String,
+
+        /// Session cookie of the site
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+    },
+}
+
+//--------------------------------------------------------------------------------
+// day selection
+//--------------------------------------------------------------------------------
+
+pub const ALL_DAYS: std::ops::RangeInclusive<u32> = 1..=25;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Days(pub Vec<u32>);
+
+fn parse_day(s: &str) -> Result<u32, String> {
+    let day = s.trim().parse::<u32>().map_err(|_| format!("invalid day '{}'", s))?;
+    if !ALL_DAYS.contains(&day) {
+        return Err(format!("day {} is not between 1 and 25", day));
+    }
+    Ok(day)
+}
+
+// "all", "17", "1,3,5", "10..15" (exclusive) or "10..=15" (inclusive), like rust ranges
+pub fn parse_days(s: &str) -> Result<Days, String> {
+    if s == "all" {
+        return Ok(Days(ALL_DAYS.collect()));
+    }
+    let mut days = vec![];
+    for item in s.split(',') {
+        if let Some((from, to)) = item.split_once("..=") {
+            days.extend(parse_day(from)?..=parse_day(to)?);
+        } else if let Some((from, to)) = item.split_once("..") {
+            days.extend(parse_day(from)?..parse_day(to)?);
+        } else {
+            days.push(parse_day(item)?);
+        }
+    }
+    days.sort();
+    days.dedup();
+    if days.is_empty() {
+        return Err(format!("no days selected by '{}'", s));
+    }
+    Ok(Days(days))
+}
+
+//--------------------------------------------------------------------------------
+// params
+//--------------------------------------------------------------------------------
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s.split_once('=').ok_or(format!("expected NAME=VALUE, got '{}'", s))?;
+    Ok((name.to_string(), value.to_string()))
+}
+
+//--------------------------------------------------------------------------------
+// timeout
+//--------------------------------------------------------------------------------
+
+// seconds, fractions allowed: "5", "0.5"
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let secs = s
+        .parse::<f64>()
+        .map_err(|_| format!("invalid number of seconds '{}'", s))?;
+    Duration::try_from_secs_f64(secs)
+        .map_err(|_| format!("the timeout must be a positive number of seconds, got '{}'", s))
+}
+
+//--------------------------------------------------------------------------------
+// output
+//--------------------------------------------------------------------------------
+
+fn parse_format(s: &str) -> Result<Format, String> {
+    match s {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(format!("unknown format '{}' (text or json)", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(parse_days("17"), Ok(Days(vec![17])));
+        assert_eq!(parse_days("3,1,3"), Ok(Days(vec![1, 3])));
+        assert_eq!(parse_days("10..13"), Ok(Days(vec![10, 11, 12])));
+        assert_eq!(parse_days("10..=13"), Ok(Days(vec![10, 11, 12, 13])));
+        assert_eq!(parse_days("all").unwrap().0.len(), 25);
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5..5").is_err());
+
+        assert_eq!(parse_timeout("0.5"), Ok(Duration::from_millis(500)));
+        assert!(["-1", "nan", "inf", "abc"].iter().all(|s| parse_timeout(s).is_err()));
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fs, path::Path};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+//--------------------------------------------------------------------------------
+// config
+//--------------------------------------------------------------------------------
+
+// the base url can point to a local server (tests, mirrors), the session is the site's cookie
+#[derive(Debug, Clone)]
+pub struct FetchConfig {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub year: u32,
+    pub data_dir: String,
+}
+
+impl FetchConfig {
+    pub(crate) fn url(&self, day: u32, path: &str) -> String {
+        format!(
+            "{}/{}/day/{}{}",
+            self.base_url.trim_end_matches('/'),
+            self.year,
+            day,
+            path
+        )
+    }
+
+    fn request(&self, method: &str, url: &str) -> Result<ureq::Request, String> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or("no session token (use --session or set AOC_SESSION)")?;
+        Ok(ureq::request(method, url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", "aoc24 runner"))
+    }
+
+    fn get(&self, url: &str) -> Result<String, String> {
+        let response = self
+            .request("GET", url)?
+            .call()
+            .map_err(|err| format!("GET {}: {}", url, err))?;
+        response.into_string().map_err(|err| format!("GET {}: {}", url, err))
+    }
+
+    pub(crate) fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let response = self
+            .request("POST", url)?
+            .send_form(form)
+            .map_err(|err| format!("POST {}: {}", url, err))?;
+        response.into_string().map_err(|err| format!("POST {}: {}", url, err))
+    }
+}
+
+//--------------------------------------------------------------------------------
+// fetch
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fetched {
+    Downloaded(String),
+    Cached(String), // already there, never downloaded again
+}
+
+// what we download for a day: the input, and the puzzle page (for the samples and their answers)
+pub fn day_files(config: &FetchConfig, day: u32) -> Vec<(String, String)> {
+    vec![
+        (
+            config.url(day, "/input"),
+            format!("{}/{:02}_input.txt", config.data_dir, day),
+        ),
+        (
+            config.url(day, ""),
+            format!("{}/{:02}_puzzle.html", config.data_dir, day),
+        ),
+    ]
+}
+
+pub fn fetch_day(config: &FetchConfig, day: u32) -> Result<Vec<Fetched>, String> {
+    let mut fetched = vec![];
+    for (url, file) in day_files(config, day) {
+        if Path::new(&file).exists() {
+            fetched.push(Fetched::Cached(file));
+            continue;
+        }
+        // nothing is written if the download fails, so the next fetch tries again
+        let content = config.get(&url)?;
+        fs::create_dir_all(&config.data_dir).map_err(|err| format!("cannot create {}: {}", config.data_dir, err))?;
+        fs::write(&file, content).map_err(|err| format!("cannot write {}: {}", file, err))?;
+        fetched.push(Fetched::Downloaded(file));
+    }
+    Ok(fetched)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::*;
+
+    // a tiny http server: answers every request with the path it got, and records the request lines
+    fn stub_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut lines = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    lines.push(line.trim().to_string());
+                }
+                let path = lines[0].split(' ').nth(1).unwrap().to_string();
+                let (status, body) = match lines.iter().any(|l| l == "Cookie: session=abc") {
+                    true => ("200 OK", format!("content of {}\n", path)),
+                    false => ("400 Bad Request", String::from("no session")),
+                };
+                recorded.lock().unwrap().push(lines[0].clone());
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (format!("http://{}", addr), requests)
+    }
+
+    #[test]
+    fn test() {
+        let (base_url, requests) = stub_server();
+        let data_dir = std::env::temp_dir().join(format!("aoc24_fetch_{}", std::process::id()));
+        let mut config = FetchConfig {
+            base_url,
+            session: Some(String::from("wrong")),
+            year: 2024,
+            data_dir: data_dir.to_string_lossy().to_string(),
+        };
+
+        // a failed download writes nothing
+        assert!(fetch_day(&config, 3).is_err());
+        assert!(!data_dir.join("03_input.txt").exists());
+
+        config.session = Some(String::from("abc"));
+        let fetched = fetch_day(&config, 3).unwrap();
+        assert!(matches!(fetched[0], Fetched::Downloaded(_)));
+        let input = fs::read_to_string(data_dir.join("03_input.txt")).unwrap();
+        assert_eq!(input, "content of /2024/day/3/input\n");
+        let page = fs::read_to_string(data_dir.join("03_puzzle.html")).unwrap();
+        assert_eq!(page, "content of /2024/day/3\n");
+
+        // second time, everything comes from the cache
+        let nb_requests = requests.lock().unwrap().len();
+        assert!(fetch_day(&config, 3)
+            .unwrap()
+            .iter()
+            .all(|f| matches!(f, Fetched::Cached(_))));
+        assert_eq!(requests.lock().unwrap().len(), nb_requests);
+
+        fs::remove_dir_all(&data_dir).unwrap();
+    }
+}
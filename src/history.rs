@@ -0,0 +1,182 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::Outcome;
+use crate::utils::*;
+
+// one json entry per line, appended after each run (not committed, it's a local record)
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+//--------------------------------------------------------------------------------
+// entries
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Entry {
+    pub timestamp: u64,
+    #[serde(default = "first_year")] // older entries have no year
+    pub year: u32,
+    pub commit: Option<String>,
+    pub profile: String,
+    pub day: u32,
+    pub part: u8,
+    pub file: String,
+    pub duration_ns: u64,
+}
+
+// the history started before there were several years
+fn first_year() -> u32 {
+    2024
+}
+
+impl Entry {
+    pub fn from_outcome(outcome: &Outcome, commit: &Option<String>) -> Entry {
+        Entry {
+            timestamp: now_secs(),
+            year: outcome.year,
+            commit: commit.clone(),
+            profile: build_profile().to_string(),
+            day: outcome.day,
+            part: outcome.part,
+            file: outcome.file.clone(),
+            duration_ns: outcome.duration.as_nanos() as u64,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+}
+
+pub fn append(file: &str, entries: &[Entry]) -> Result<(), String> {
+    append_jsonl(file, entries)
+}
+
+pub fn load(file: &str) -> Result<Vec<Entry>, String> {
+    load_jsonl(file)
+}
+
+//--------------------------------------------------------------------------------
+// report
+//--------------------------------------------------------------------------------
+
+// the latest timing of a part, compared to the best one before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub latest: Entry,
+    pub best: Option<Entry>,
+    pub slower: bool,
+}
+
+impl Comparison {
+    // how much slower (positive) or faster (negative) than the previous best, in %
+    pub fn change_pct(&self) -> Option<f64> {
+        let best = self.best.as_ref()?;
+        Some((self.latest.duration_ns as f64 / best.duration_ns.max(1) as f64 - 1.0) * 100.0)
+    }
+}
+
+// entries are grouped by (profile, year, day, part, file): debug and release timings are never compared.
+// a part is slower if it's over the threshold (in %) and the difference is more than `min_diff`
+// (so a 2µs -> 3µs jitter doesn't get flagged)
+pub fn compare(entries: &[Entry], threshold_pct: f64, min_diff: Duration) -> Vec<Comparison> {
+    let mut groups: BTreeMap<(u32, u32, u8, String, String), Vec<&Entry>> = BTreeMap::new();
+    for entry in entries {
+        let key = (
+            entry.year,
+            entry.day,
+            entry.part,
+            entry.file.clone(),
+            entry.profile.clone(),
+        );
+        groups.entry(key).or_default().push(entry);
+    }
+
+    let mut comparisons = vec![];
+    for (_, group) in groups {
+        let (latest, previous) = group.split_last().unwrap();
+        let best = previous.iter().min_by_key(|e| e.duration_ns).map(|&e| e.clone());
+        let slower = best.as_ref().is_some_and(|best| {
+            let limit = best.duration_ns as f64 * (1.0 + threshold_pct / 100.0);
+            latest.duration_ns as f64 > limit && latest.duration() > best.duration() + min_diff
+        });
+        comparisons.push(Comparison {
+            latest: (*latest).clone(),
+            best,
+            slower,
+        });
+    }
+    comparisons
+}
+
+pub fn pp_comparison(c: &Comparison) {
+    let best = match (&c.best, c.change_pct()) {
+        (Some(best), Some(pct)) => format!(
+            "best {} ({}) {:+.0}%",
+            fmt_duration(best.duration()),
+            best.commit.as_deref().unwrap_or("?"),
+            pct
+        ),
+        _ => String::from("first run"),
+    };
+    println!(
+        "{} day{:02} p{} : {} [{}] {} -> {}, {}",
+        if c.slower { "SLOWER" } else { "      " },
+        c.latest.day,
+        c.latest.part,
+        c.latest.file,
+        c.latest.profile,
+        c.latest.commit.as_deref().unwrap_or("?"),
+        fmt_duration(c.latest.duration()),
+        best
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, profile: &str, duration_ns: u64) -> Entry {
+        Entry {
+            timestamp: 0,
+            year: 2024,
+            commit: Some(commit.to_string()),
+            profile: profile.to_string(),
+            day: 6,
+            part: 2,
+            file: "data/06_input.txt".to_string(),
+            duration_ns,
+        }
+    }
+
+    #[test]
+    fn test() {
+        let ms = 1_000_000;
+        let min_diff = Duration::from_millis(1);
+
+        // slower than the best, even if the previous run was slow too
+        let entries = vec![
+            entry("a", "release", 100 * ms),
+            entry("b", "release", 150 * ms),
+            entry("c", "release", 130 * ms),
+        ];
+        let c = compare(&entries, 20.0, min_diff);
+        assert_eq!(c.len(), 1);
+        assert!(c[0].slower);
+        assert_eq!(c[0].best.as_ref().unwrap().commit.as_deref(), Some("a"));
+
+        // within the threshold
+        assert!(!compare(&entries, 50.0, min_diff)[0].slower);
+
+        // debug is not compared to release
+        let entries = vec![entry("a", "release", 100 * ms), entry("b", "debug", 2000 * ms)];
+        let c = compare(&entries, 20.0, min_diff);
+        assert_eq!(c.len(), 2);
+        assert!(c.iter().all(|c| !c.slower));
+
+        // tiny timings are just noise
+        let entries = vec![entry("a", "release", 2000), entry("b", "release", 5000)];
+        assert!(!compare(&entries, 20.0, min_diff)[0].slower);
+    }
+}
@@ -0,0 +1,82 @@
+// the days and the tools around them (parsing, grids, runner, answers...), usable from other crates:
+// `aoc24::utils::Matrix`, `aoc24::y2024::day06::Day06::parse(...)`, or any day through the `YEARS` registry
+
+// the inputs are parsed with inherent `from_str` constructors, not with the FromStr trait
+#![allow(clippy::should_implement_trait)]
+
+use solution::Day;
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod fetch;
+pub mod history;
+pub mod parse;
+pub mod prop;
+pub mod runner;
+pub mod samples;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod tiles;
+pub mod utils;
+pub mod watch;
+
+pub mod y2024;
+
+// each season: its days are in src/yYEAR/, its inputs in data/YEAR/ and its answers in answers/YEAR.toml.
+// `new` adds the first day of a year here
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+pub const YEARS: &[Year] = &[Year {
+    year: 2024,
+    days: y2024::DAYS,
+}];
+
+pub fn latest_year() -> u32 {
+    YEARS.iter().map(|y| y.year).max().unwrap()
+}
+
+pub fn find_year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+pub fn find_day(year: u32, day: u32) -> Option<&'static Day> {
+    find_year(year)?.days.iter().find(|d| d.day == day)
+}
+
+pub fn data_dir(year: u32) -> String {
+    format!("data/{}", year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        // days in order, in the right year, and every declared input exists
+        for year in YEARS {
+            assert!(year.days.windows(2).all(|w| w[0].day < w[1].day));
+            for day in year.days {
+                assert_eq!(day.year, year.year);
+                for run in day.runs {
+                    assert!(
+                        run.file.starts_with(&data_dir(year.year)),
+                        "{} is not in its year",
+                        run.file
+                    );
+                    assert!(std::path::Path::new(run.file).exists(), "{} is missing", run.file);
+                    if let Err(err) = day.params_for(run) {
+                        panic!("{}: {}", day.name(), err);
+                    }
+                }
+            }
+        }
+        assert_eq!(find_year(2024).unwrap().days.len(), 25);
+    }
+}
@@ -1,58 +1,321 @@
-#![feature(array_chunks)]
-#![allow(dead_code)]
-
-mod utils;
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+use std::time::Duration;
+
+use clap::Parser;
+
+use aoc24::alloc::CountingAlloc;
+use aoc24::answers::{self, answers_file, Answers, Status};
+use aoc24::bench::{self, BenchReport};
+use aoc24::fetch::{self, FetchConfig, Fetched};
+use aoc24::history::{self, HISTORY_FILE};
+use aoc24::runner::{self, Format, Outcome, Record};
+use aoc24::samples::{self, Sample};
+use aoc24::solution::Answer;
+use aoc24::submit::{self, Verdict, GUESSES_FILE};
+use aoc24::tiles::{self, README_FILE, TILES_DIR};
+use aoc24::watch;
+use aoc24::{data_dir, find_day, find_year, latest_year, scaffold, utils};
+use cli::{Cli, Command, Days, ALL_DAYS};
+
+mod cli;
+
+// counts allocations for `bench`
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn exit_with(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    std::process::exit(2);
+}
+
+// keep a record of the timings, for `report` (parts that didn't run have no timing)
+fn record(outcomes: &[Outcome]) {
+    let commit = utils::git_commit();
+    let entries = outcomes
+        .iter()
+        .filter(|o| !matches!(o.status, Status::Error(_) | Status::Timeout(_)))
+        .map(|o| history::Entry::from_outcome(o, &commit))
+        .collect::<Vec<_>>();
+    if let Err(err) = history::append(HISTORY_FILE, &entries) {
+        eprintln!("{}", err);
+    }
+}
 
 fn main() {
-    day25::run();
-    day24::run();
-    day23::run();
-    day22::run();
-    day21::run();
-    day20::run();
-    day19::run();
-    day18::run();
-    day17::run();
-    day16::run();
-    day15::run();
-    day14::run();
-    day13::run();
-    day12::run();
-    day11::run();
-    day10::run();
-    day09::run();
-    day08::run();
-    day07::run();
-    day06::run();
-    day05::run();
-    day04::run();
-    day03::run();
-    day02::run();
-    day01::run();
+    let cli = Cli::parse();
+    let command = cli.command.unwrap_or(Command::Run {
+        days: Days(ALL_DAYS.collect()),
+        part: None,
+        input: None,
+        all_inputs: false,
+        params: vec![],
+        timeout: None,
+        format: Format::Text,
+    });
+    let year = cli.year.unwrap_or(latest_year());
+    // new and fetch start a year, everything else needs it solved already
+    let solved = match (&command, find_year(year)) {
+        (_, Some(y)) => y.days,
+        (Command::New { .. } | Command::Fetch { .. }, None) => &[],
+        (_, None) => exit_with(&format!("no day of {} is solved yet", year)),
+    };
+    let answers_file = answers_file(year);
+    let answers = match solved.is_empty() {
+        true => Answers::default(),
+        false => Answers::load(&answers_file).unwrap_or_else(|err| exit_with(&err)),
+    };
+
+    match command {
+        Command::Run {
+            days,
+            part,
+            input,
+            all_inputs,
+            params,
+            timeout,
+            format,
+        } => {
+            if input.is_some() && days.0.len() > 1 {
+                exit_with("--input can only be used with a single day");
+            }
+            for day in days.0 {
+                match find_day(year, day) {
+                    Some(d) if part.is_none_or(|p| d.has_part(p)) => {
+                        let jobs = match all_inputs {
+                            true => runner::plan_files(d, part, &watch::data_files(&data_dir(year), day)),
+                            false => runner::plan(d, part, input.as_deref(), &params),
+                        };
+                        let jobs = jobs.unwrap_or_else(|err| exit_with(&format!("{}: {}", d.name(), err)));
+                        record(&runner::run_jobs(d, &jobs, &answers, timeout, format));
+                    }
+                    Some(d) => eprintln!("{} has no part {}", d.name(), part.unwrap()),
+                    None => eprintln!("day{:02} is not solved yet", day),
+                }
+            }
+        }
+
+        Command::All {
+            jobs,
+            part,
+            timeout,
+            format,
+        } => {
+            let nb_threads = jobs.unwrap_or(std::thread::available_parallelism().map_or(1, |n| n.get()));
+            let mut all_jobs = vec![];
+            for d in solved.iter().filter(|d| part.is_none_or(|p| d.has_part(p))) {
+                let jobs = runner::plan(d, part, None, &[]).unwrap_or_else(|err| exit_with(&err));
+                all_jobs.extend(jobs.into_iter().map(|job| (d, job)));
+            }
+            let ((outcomes, diagnostics, jobs_time), wall) =
+                utils::time_it(|| runner::run_parallel(&all_jobs, &answers, timeout, nb_threads));
+            for diagnostic in diagnostics {
+                eprint!("{}", diagnostic);
+            }
+            match format {
+                Format::Text => runner::pp_summary(&outcomes, wall, jobs_time),
+                Format::Json => outcomes
+                    .iter()
+                    .for_each(|o| println!("{}", Record::from_outcome(o).to_json())),
+            }
+            record(&outcomes);
+        }
+
+        Command::Watch {
+            day,
+            release,
+            interval_ms,
+        } => watch::watch(year, day, release, Duration::from_millis(interval_ms)),
+
+        Command::Bench {
+            days,
+            part,
+            runs,
+            warmup,
+            output,
+        } => {
+            // `measure` always runs once, the report says so
+            let runs = runs.max(1);
+            let mut results = vec![];
+            for d in days.0.into_iter().filter_map(|day| find_day(year, day)) {
+                let jobs = runner::plan(d, part, None, &[]).unwrap_or_else(|err| exit_with(&err));
+                results.extend(bench::bench_jobs(d, &jobs, warmup, runs));
+            }
+            let report = BenchReport {
+                year,
+                commit: utils::git_commit(),
+                profile: utils::build_profile(),
+                timestamp: utils::now_secs(),
+                warmup,
+                runs,
+                results,
+            };
+            report.write(&output).unwrap_or_else(|err| exit_with(&err));
+            println!("\nresults written to {}", output);
+        }
+
+        Command::Verify { days, timeout, format } => {
+            let days = days.map_or(ALL_DAYS.collect(), |days| days.0);
+            let mut outcomes = vec![];
+            for d in days.into_iter().filter_map(|day| find_day(year, day)) {
+                let jobs =
+                    runner::plan_verify(d, &answers).unwrap_or_else(|err| exit_with(&format!("{}: {}", d.name(), err)));
+                outcomes.extend(runner::run_jobs(d, &jobs, &answers, timeout, format));
+            }
+            record(&outcomes);
+            let nb_failed = outcomes
+                .iter()
+                .filter(|o| matches!(o.status, Status::Fail(_) | Status::Error(_) | Status::Timeout(_)))
+                .count();
+            if format == Format::Text {
+                println!("\n{} answers checked, {} failed", outcomes.len(), nb_failed);
+            }
+            if nb_failed > 0 {
+                std::process::exit(1);
+            }
+        }
+
+        Command::Report { threshold, min_diff_ms } => {
+            let entries = history::load(HISTORY_FILE).unwrap_or_else(|err| exit_with(&err));
+            let comparisons = history::compare(&entries, threshold, Duration::from_millis(min_diff_ms));
+            for c in &comparisons {
+                history::pp_comparison(c);
+            }
+            let nb_slower = comparisons.iter().filter(|c| c.slower).count();
+            println!(
+                "\n{} parts tracked, {} slower than their best by more than {}%",
+                comparisons.len(),
+                nb_slower,
+                threshold
+            );
+            if nb_slower > 0 {
+                std::process::exit(1);
+            }
+        }
+
+        Command::Samples { day, html } => {
+            let html_file = html.unwrap_or(format!("{}/{:02}_puzzle.html", data_dir(year), day));
+            let html = std::fs::read_to_string(&html_file)
+                .unwrap_or_else(|err| exit_with(&format!("cannot read {}: {}", html_file, err)));
+            let parts = samples::parse_html(&html);
+            let blocks = parts.iter().flat_map(|part| part.samples.clone()).collect::<Vec<_>>();
+            let written = samples::write_samples(&data_dir(year), day, &blocks).unwrap_or_else(|err| exit_with(&err));
+            for sample in &written {
+                match sample {
+                    Sample::Written(file) => println!("{} written", file),
+                    Sample::Existing(file) => println!("{} already there", file),
+                }
+            }
+
+            let mut manifest = std::fs::read_to_string(&answers_file).unwrap_or(answers::manifest_header(year));
+            for (file, expected) in samples::expected_answers(&parts, &written) {
+                if answers.has(day, &file) {
+                    continue;
+                }
+                manifest = answers::add_entry(&manifest, day, &file, &expected);
+                println!("{} added to {}", file, answers_file);
+            }
+            std::fs::write(&answers_file, manifest).unwrap_or_else(|err| exit_with(&err.to_string()));
+        }
+
+        Command::New { day, title } => {
+            let changed = scaffold::new_day("src", year, day, &title).unwrap_or_else(|err| exit_with(&err));
+            for file in changed {
+                println!("{}", file);
+            }
+        }
+
+        Command::Fetch {
+            days,
+            base_url,
+            session,
+        } => {
+            let config = FetchConfig {
+                base_url,
+                session,
+                year,
+                data_dir: data_dir(year),
+            };
+            for day in days.0 {
+                for fetched in fetch::fetch_day(&config, day).unwrap_or_else(|err| exit_with(&err)) {
+                    match fetched {
+                        Fetched::Downloaded(file) => println!("{} downloaded", file),
+                        Fetched::Cached(file) => println!("{} already there", file),
+                    }
+                }
+            }
+        }
+
+        Command::Tiles => {
+            let mut years = vec![];
+            for y in aoc24::YEARS {
+                let answers = Answers::load(&answers::answers_file(y.year)).unwrap_or_else(|err| exit_with(&err));
+                let year_tiles = tiles::year_tiles(y, &answers);
+                tiles::write_tiles(TILES_DIR, y.year, &year_tiles).unwrap_or_else(|err| exit_with(&err));
+                println!(
+                    "{}: {} days, {} stars",
+                    y.year,
+                    year_tiles.len(),
+                    tiles::nb_stars(&year_tiles)
+                );
+                years.push((y.year, year_tiles));
+            }
+            let readme = std::fs::read_to_string(README_FILE).unwrap_or_else(|err| exit_with(&err.to_string()));
+            let readme =
+                tiles::replace_block(&readme, &tiles::readme_block(&years)).unwrap_or_else(|err| exit_with(&err));
+            std::fs::write(README_FILE, readme).unwrap_or_else(|err| exit_with(&err.to_string()));
+            println!("{} updated", README_FILE);
+        }
+
+        Command::Submit {
+            day,
+            part,
+            base_url,
+            session,
+        } => {
+            let d = find_day(year, day).unwrap_or_else(|| exit_with(&format!("day{:02} is not solved yet", day)));
+            let input = format!("{}/{:02}_input.txt", data_dir(year), day);
+            let jobs = runner::plan(d, Some(part), Some(&input), &[]).unwrap_or_else(|err| exit_with(&err));
+            let job = jobs
+                .first()
+                .unwrap_or_else(|| exit_with(&format!("{} has no part {}", d.name(), part)));
+            let (outcomes, diagnostic) = runner::solve_job(d, job, &answers, None);
+            let outcome = &outcomes[0];
+            match (&outcome.status, &outcome.answer) {
+                (Status::Error(err), _) => exit_with(&diagnostic.unwrap_or(err.clone())),
+                (Status::Fail(expected), _) => exit_with(&format!(
+                    "{} doesn't match the manifest ({}), not sending it",
+                    outcome.answer, expected
+                )),
+                (_, Answer::None) => exit_with("no answer to send"),
+                _ => {}
+            }
+
+            let config = FetchConfig {
+                base_url,
+                session,
+                year,
+                data_dir: data_dir(year),
+            };
+            let answer = outcome.answer.to_string();
+            println!("sending {} for {} p{}", answer, d.name(), part);
+            let guess = submit::submit(&config, GUESSES_FILE, day, part, &answer).unwrap_or_else(|err| exit_with(&err));
+            match guess.verdict {
+                Verdict::Right => println!(
+                    "right! it can go in {}: \"{}\" p{} = \"{}\"",
+                    answers_file, input, part, answer
+                ),
+                Verdict::Wrong => println!("wrong"),
+                Verdict::TooHigh => println!("wrong, too high"),
+                Verdict::TooLow => println!("wrong, too low"),
+                Verdict::Wait => println!("too soon, nothing was checked"),
+                Verdict::AlreadySolved => println!("already solved (or not unlocked yet)"),
+                Verdict::Unknown => println!("unknown response, see the site"),
+            }
+            if let Some(until) = guess.wait_until {
+                println!(
+                    "next submission in {}",
+                    utils::fmt_duration(Duration::from_secs(until - guess.timestamp))
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,156 @@
+use std::{fmt, str::FromStr};
+
+//--------------------------------------------------------------------------------
+// error
+//--------------------------------------------------------------------------------
+
+// what we expected, and the token we found instead. the token is a slice of the input, so
+// `locate` can find its line and column from its address once we know the whole file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize, // 1-based, 0 if unknown
+    pub col: usize,  // 1-based, in chars
+    pub expected: String,
+    pub found: String,
+    addr: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(token: &str, expected: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line: 0,
+            col: 0,
+            expected: expected.to_string(),
+            found: token.to_string(),
+            addr: Some(token.as_ptr() as usize),
+        }
+    }
+
+    // for tokens that are not slices of the input (a char...): no position
+    pub fn unlocated(found: &str, expected: &str) -> ParseError {
+        ParseError {
+            addr: None,
+            ..ParseError::new(found, expected)
+        }
+    }
+
+    // find the line and column of the token in `content` (if it comes from it)
+    pub fn locate(mut self, file: &str, content: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        let start = content.as_ptr() as usize;
+        if let Some(addr) = self.addr.filter(|&addr| addr >= start && addr <= start + content.len()) {
+            let before = &content[..addr - start];
+            self.line = before.matches('\n').count() + 1;
+            self.col = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        }
+        self
+    }
+
+    // rustc style: the message, the location, and a caret under the token
+    pub fn pp(&self, content: &str) -> String {
+        let mut s = format!("error: expected {}, found {}\n", self.expected, self.pp_found());
+        if let Some(file) = &self.file {
+            match self.line {
+                0 => s += &format!("  --> {}\n", file),
+                _ => s += &format!("  --> {}:{}:{}\n", file, self.line, self.col),
+            }
+        }
+        if let Some(line) = content.lines().nth(self.line.wrapping_sub(1)) {
+            let margin = " ".repeat(self.line.to_string().len());
+            let carets = "^".repeat(self.found.chars().count().max(1));
+            s += &format!("{} |\n", margin);
+            s += &format!("{} | {}\n", self.line, line);
+            s += &format!("{} | {}{}\n", margin, " ".repeat(self.col - 1), carets);
+        }
+        s
+    }
+
+    fn pp_found(&self) -> String {
+        match self.found.as_str() {
+            "" => String::from("nothing"),
+            found => format!("'{}'", found),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), 0) => write!(f, "{}: ", file)?,
+            (Some(file), line) => write!(f, "{}:{}:{}: ", file, line, self.col)?,
+            _ => {}
+        }
+        write!(f, "expected {}, found {}", self.expected, self.pp_found())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+//--------------------------------------------------------------------------------
+// helpers
+//--------------------------------------------------------------------------------
+
+pub fn parse_num<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse::<T>().map_err(|_| ParseError::new(s, expected))
+}
+
+pub fn split_once<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(s, &format!("'{}'", sep)))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let end = s.char_indices().nth(prefix.chars().count()).map_or(s.len(), |(i, _)| i);
+        ParseError::new(&s[..end], &format!("'{}'", prefix))
+    })
+}
+
+// the next line, or an error pointing at the end of `block` if there is none
+pub fn next_line<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    block: &'a str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    lines
+        .next()
+        .ok_or_else(|| ParseError::new(&block[block.len()..], expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let content = "Register A: 729\nProgram: 0,x,4";
+        let prg = content.lines().nth(1).unwrap();
+        let token = prg.split(',').nth(1).unwrap();
+        let err = parse_num::<u64>(token, "a number")
+            .unwrap_err()
+            .locate("f.txt", content);
+        assert_eq!((err.line, err.col), (2, 12));
+        assert_eq!(err.to_string(), "f.txt:2:12: expected a number, found 'x'");
+        assert_eq!(
+            err.pp(content),
+            "error: expected a number, found 'x'\n  --> f.txt:2:12\n  |\n2 | Program: 0,x,4\n  |            ^\n"
+        );
+
+        // missing line: points at the end
+        let mut lines = content.lines();
+        lines.next();
+        lines.next();
+        let err = next_line(&mut lines, content, "a program")
+            .unwrap_err()
+            .locate("f.txt", content);
+        assert_eq!((err.line, err.col, err.found.as_str()), (2, 15, ""));
+
+        // not from the content: no position
+        let err = ParseError::unlocated("z", "a digit").locate("f.txt", content);
+        assert_eq!(err.to_string(), "f.txt: expected a digit, found 'z'");
+
+        assert_eq!(strip_prefix("Button A: X+1", "Button B").unwrap_err().found, "Button A");
+    }
+}
@@ -0,0 +1,237 @@
+use std::panic::{self, AssertUnwindSafe};
+
+// property tests: random inputs from a seed, checked against a slow reference, and the first
+// failing case shrunk to something small enough to debug by hand
+
+//--------------------------------------------------------------------------------
+// random
+//--------------------------------------------------------------------------------
+
+// splitmix64: tiny, good enough for tests, and the same sequence for the same seed everywhere
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // in 0..n
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n.max(1)
+    }
+
+    // in lo..=hi
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo + 1) as u64) as i64
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+//--------------------------------------------------------------------------------
+// shrinking
+//--------------------------------------------------------------------------------
+
+// the list without one of its elements (a half first, to go faster on long lists)
+pub fn shrink_vec<T: Clone>(v: &[T]) -> Vec<Vec<T>> {
+    let mut res = vec![];
+    if v.len() > 1 {
+        res.push(v[..v.len() / 2].to_vec());
+        res.push(v[v.len() / 2..].to_vec());
+    }
+    for i in 0..v.len() {
+        let mut smaller = v.to_vec();
+        smaller.remove(i);
+        res.push(smaller);
+    }
+    res
+}
+
+// the grid without one of its rows or columns, or with a cell set to `filler`
+pub fn shrink_grid(grid: &[Vec<char>], filler: char) -> Vec<Vec<Vec<char>>> {
+    let mut res = vec![];
+    if grid.len() > 1 {
+        for y in 0..grid.len() {
+            let mut smaller = grid.to_vec();
+            smaller.remove(y);
+            res.push(smaller);
+        }
+    }
+    if grid.first().is_some_and(|row| row.len() > 1) {
+        for x in 0..grid[0].len() {
+            let smaller = grid
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|(i, _)| *i != x)
+                        .map(|(_, &c)| c)
+                        .collect()
+                })
+                .collect();
+            res.push(smaller);
+        }
+    }
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c != filler {
+                let mut simpler = grid.to_vec();
+                simpler[y][x] = filler;
+                res.push(simpler);
+            }
+        }
+    }
+    res
+}
+
+pub fn grid_to_string(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+//--------------------------------------------------------------------------------
+// check
+//--------------------------------------------------------------------------------
+
+// how many cases and from which seed: PROP_CASES and PROP_SEED override the defaults, to search
+// longer or to replay a failure
+pub fn config() -> (u64, usize) {
+    let env = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok());
+    (
+        env("PROP_SEED").unwrap_or(2024),
+        env("PROP_CASES").unwrap_or(100) as usize,
+    )
+}
+
+#[derive(Debug, Clone)]
+pub struct Failure<T> {
+    pub seed: u64,
+    pub original: T,
+    pub case: T, // shrunk
+    pub message: String,
+    pub nb_shrinks: usize,
+}
+
+impl<T> Failure<T> {
+    pub fn pp(&self, show: impl Fn(&T) -> String) -> String {
+        format!(
+            "failed with seed {} (PROP_SEED={} to replay): {}\nshrunk case ({} steps):\n{}\n",
+            self.seed,
+            self.seed,
+            self.message,
+            self.nb_shrinks,
+            show(&self.case)
+        )
+    }
+}
+
+// a panic is a failure too
+fn run_check<T>(check: &impl Fn(&T) -> Result<(), String>, case: &T) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| check(case))) {
+        Ok(res) => res,
+        Err(err) => Err(match (err.downcast_ref::<&str>(), err.downcast_ref::<String>()) {
+            (Some(msg), _) => format!("panicked: {}", msg),
+            (_, Some(msg)) => format!("panicked: {}", msg),
+            _ => String::from("panicked"),
+        }),
+    }
+}
+
+// `nb_cases` cases, growing from size 1 to `max_size`. each case has its own seed, so a failure
+// can be replayed alone. a failing case is shrunk while one of its simpler versions still fails
+pub fn check_cases<T: Clone>(
+    seed: u64,
+    nb_cases: usize,
+    max_size: usize,
+    gen: impl Fn(&mut Rng, usize) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    check: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), Failure<T>> {
+    for i in 0..nb_cases {
+        let case_seed = seed.wrapping_add(i as u64);
+        let size = 1 + i * max_size.saturating_sub(1) / nb_cases.max(1);
+        let original = gen(&mut Rng::new(case_seed), size);
+        let Err(mut message) = run_check(&check, &original) else {
+            continue;
+        };
+
+        let mut case = original.clone();
+        let mut nb_shrinks = 0;
+        while nb_shrinks < 1000 {
+            let smaller = shrink(&case)
+                .into_iter()
+                .find_map(|c| run_check(&check, &c).err().map(|msg| (c, msg)));
+            let Some((c, msg)) = smaller else {
+                break;
+            };
+            (case, message) = (c, msg);
+            nb_shrinks += 1;
+        }
+        return Err(Failure {
+            seed: case_seed,
+            original,
+            case,
+            message,
+            nb_shrinks,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut rng = Rng::new(1);
+        let values = (0..1000).map(|_| rng.range(-2, 2)).collect::<Vec<_>>();
+        assert!((-2..=2).all(|v| values.contains(&v)));
+        assert!(values.iter().all(|v| (-2..=2).contains(v)));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+
+        // "no list contains a 7" is wrong: the failure shrinks down to [7]
+        let failure = check_cases(
+            0,
+            100,
+            20,
+            |rng, size| (0..size).map(|_| rng.below(10)).collect::<Vec<_>>(),
+            |v| shrink_vec(v),
+            |v| match v.contains(&7) {
+                true => Err(String::from("found a 7")),
+                false => Ok(()),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(failure.case, vec![7]);
+        assert_eq!(failure.message, "found a 7");
+
+        let grid = vec![vec!['#', '.'], vec!['.', '^']];
+        let smaller = shrink_grid(&grid, '.');
+        assert!(smaller.contains(&vec![vec!['.', '^']]));
+        assert!(smaller.contains(&vec![vec!['.'], vec!['^']]));
+        assert!(smaller.contains(&vec![vec!['.', '.'], vec!['.', '^']]));
+        assert_eq!(grid_to_string(&grid), "#.\n.^");
+
+        // panics are failures
+        let failure = check_cases(0, 1, 1, |_, _| 0u64, |_| vec![], |_| panic!("boom")).unwrap_err();
+        assert_eq!(failure.message, "panicked: boom");
+    }
+}
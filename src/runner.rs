@@ -0,0 +1,511 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::answers::*;
+use crate::find_day;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// plan
+//--------------------------------------------------------------------------------
+
+// what to solve for one input file: the file is parsed once, then each part is solved
+pub struct Job {
+    pub file: String,
+    pub runs: Vec<(u8, Params)>,
+}
+
+fn add_to_jobs(jobs: &mut Vec<Job>, file: &str, part: u8, params: Params) {
+    match jobs.iter_mut().find(|job| job.file == file) {
+        Some(job) => job.runs.push((part, params)),
+        None => jobs.push(Job {
+            file: file.to_string(),
+            runs: vec![(part, params)],
+        }),
+    }
+}
+
+// the runs of a day, grouped by input file. `input` replaces the declared files, and
+// `overrides` (from the command line) win over the declared params
+pub fn plan(
+    day: &Day,
+    part: Option<u8>,
+    input: Option<&str>,
+    overrides: &[(String, String)],
+) -> Result<Vec<Job>, String> {
+    let runs: Vec<(&str, &Run)> = match input {
+        Some(file) => [1, 2]
+            .into_iter()
+            .filter(|&p| part.is_none_or(|part| part == p))
+            .filter_map(|p| day.run_for(p, file))
+            .map(|run| (file, run))
+            .collect(),
+        None => day
+            .runs
+            .iter()
+            .filter(|run| part.is_none_or(|part| part == run.part))
+            .map(|run| (run.file, run))
+            .collect(),
+    };
+
+    let mut jobs = vec![];
+    for (file, run) in runs {
+        let mut params = day.params_for(run)?;
+        for (name, value) in overrides {
+            params.set(name, value)?;
+        }
+        add_to_jobs(&mut jobs, file, run.part, params);
+    }
+    Ok(jobs)
+}
+
+// every answer of the manifest for that day, with the params declared for its file
+pub fn plan_verify(day: &Day, answers: &Answers) -> Result<Vec<Job>, String> {
+    let mut jobs = vec![];
+    for (file, expected) in answers.files(day.day) {
+        for part in [1, 2] {
+            if expected.part(part).is_none() {
+                continue;
+            }
+            add_to_jobs(&mut jobs, file, part, day.params_for_file(part, file)?);
+        }
+    }
+    Ok(jobs)
+}
+
+// every part of the day on every one of its files (samples first), known answer or not
+pub fn plan_files(day: &Day, part: Option<u8>, files: &[String]) -> Result<Vec<Job>, String> {
+    let mut files = files.to_vec();
+    files.sort_by_key(|file| file.contains("input"));
+    let mut jobs = vec![];
+    for file in &files {
+        for p in [1, 2]
+            .into_iter()
+            .filter(|&p| day.has_part(p) && part.is_none_or(|part| part == p))
+        {
+            add_to_jobs(&mut jobs, file, p, day.params_for_file(p, file)?);
+        }
+    }
+    Ok(jobs)
+}
+
+//--------------------------------------------------------------------------------
+// run
+//--------------------------------------------------------------------------------
+
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub file: String,
+    pub answer: Answer,
+    pub read: Duration,  // of the file, shared by its parts
+    pub parse: Duration, // same
+    pub duration: Duration,
+    pub status: Status,
+}
+
+// run f on its own thread and wait for it, at most `timeout`. if it runs longer, it is left behind
+// with its cancel flag raised (see `cancelled`), and the caller moves on right away. a panic is an
+// error, with or without a timeout
+fn run_timed<R: Send + 'static>(
+    f: impl FnOnce() -> R + Send + 'static,
+    timeout: Option<Duration>,
+) -> Result<(R, Duration), Status> {
+    let Some(timeout) = timeout else {
+        return catch_panic(|| time_it(f));
+    };
+    let (tx, rx) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let flag = cancel.clone();
+    thread::spawn(move || {
+        set_cancel_flag(flag);
+        let _ = tx.send(catch_panic(|| time_it(f)));
+    });
+    match rx.recv_timeout(timeout) {
+        Ok(res) => res,
+        Err(RecvTimeoutError::Timeout) => {
+            cancel.store(true, Ordering::Relaxed);
+            Err(Status::Timeout(timeout))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(Status::Error(String::from("panicked"))),
+    }
+}
+
+// the panic message (the hook has already printed it with its location)
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Status> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+        Status::Error(msg.map_or(String::from("panicked"), |msg| format!("panicked: {}", msg)))
+    })
+}
+
+fn solve_part(
+    solver: &Arc<dyn Solver>,
+    part: u8,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<(Answer, Duration), Status> {
+    let (solver, params) = (solver.clone(), params.clone());
+    run_timed(move || solver.solve(part, &params), timeout)
+}
+
+// parse the file once and solve each part, without printing anything. parsing gets the same
+// timeout as a part. if the file can't be parsed, all its parts fail with the error, and the
+// caret diagnostic is returned too
+pub fn solve_job(day: &Day, job: &Job, answers: &Answers, timeout: Option<Duration>) -> (Vec<Outcome>, Option<String>) {
+    let (content, read) = time_it(|| read_input(&job.file));
+    // shared with the parsing thread: the tokens of a parse error still point into it
+    let content: Arc<str> = content.into();
+    let parse_input = day.parse;
+    let parsed = {
+        let content = content.clone();
+        run_timed(move || parse_input(&content), timeout)
+    };
+    let (solver, parse) = match parsed {
+        Ok((Ok(solver), parse)) => (Ok(solver), parse),
+        Ok((Err(err), parse)) => {
+            let err = err.locate(&job.file, &content);
+            (Err((Status::Error(err.to_string()), Some(err.pp(&content)))), parse)
+        }
+        Err(status @ Status::Timeout(timeout)) => (Err((status, None)), timeout),
+        Err(status) => (Err((status, None)), Duration::ZERO),
+    };
+    let outcome = |part: u8, answer: Answer, duration: Duration, status: Status| Outcome {
+        year: day.year,
+        day: day.day,
+        part,
+        file: job.file.clone(),
+        answer,
+        read,
+        parse,
+        duration,
+        status,
+    };
+    let solver: Arc<dyn Solver> = match solver {
+        Ok(solver) => solver.into(),
+        Err((status, diagnostic)) => {
+            let outcomes = job
+                .runs
+                .iter()
+                .map(|(part, _)| outcome(*part, Answer::None, Duration::ZERO, status.clone()))
+                .collect();
+            return (outcomes, diagnostic);
+        }
+    };
+    let mut outcomes = vec![];
+    for (part, params) in &job.runs {
+        match solve_part(&solver, *part, params, timeout) {
+            Ok((answer, duration)) => {
+                let status = answers.check(day.day, &job.file, *part, &answer.to_string());
+                outcomes.push(outcome(*part, answer, duration, status));
+            }
+            Err(status @ Status::Timeout(timeout)) => outcomes.push(outcome(*part, Answer::None, timeout, status)),
+            Err(status) => outcomes.push(outcome(*part, Answer::None, Duration::ZERO, status)),
+        }
+    }
+    (outcomes, None)
+}
+
+pub fn run_jobs(day: &Day, jobs: &[Job], answers: &Answers, timeout: Option<Duration>, format: Format) -> Vec<Outcome> {
+    if format == Format::Text {
+        pp_day(&day.name());
+    }
+    let mut outcomes = vec![];
+    for job in jobs {
+        let (job_outcomes, diagnostic) = solve_job(day, job, answers, timeout);
+        if let Some(diagnostic) = diagnostic {
+            // the parts of that file can't run, but the other files can
+            eprint!("{}", diagnostic);
+        }
+        for o in job_outcomes {
+            match format {
+                Format::Text => {
+                    pp_result(
+                        &format!("p{}", o.part),
+                        &o.file,
+                        o.duration,
+                        &o.answer.to_string(),
+                        &o.status,
+                    );
+                    if let Status::Fail(expected) = &o.status {
+                        print!("{}", pp_diff(expected, &o.answer.to_string()));
+                    }
+                }
+                Format::Json => println!("{}", Record::from_outcome(&o).to_json()),
+            }
+            outcomes.push(o);
+        }
+    }
+    outcomes
+}
+
+//--------------------------------------------------------------------------------
+// json output
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json, // one record per line
+}
+
+// what scripts get for each part, instead of the `[12ms] p1 : file -> 42 PASS` line
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub part: u8,
+    pub file: String,
+    pub answer: Option<String>,
+    pub answer_type: Option<&'static str>, // "number", "string", "position" or "none"
+    pub read_ns: u64,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub status: &'static str, // PASS, FAIL, UNKNOWN, ERROR or TIMEOUT
+    pub expected: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn from_outcome(o: &Outcome) -> Record {
+        let answered = !matches!(o.status, Status::Error(_) | Status::Timeout(_));
+        let (status, expected, error) = match &o.status {
+            Status::Pass => ("PASS", None, None),
+            Status::Fail(expected) => ("FAIL", Some(expected.clone()), None),
+            Status::Unknown => ("UNKNOWN", None, None),
+            Status::Error(err) => ("ERROR", None, Some(err.clone())),
+            Status::Timeout(_) => ("TIMEOUT", None, None),
+        };
+        Record {
+            year: o.year,
+            day: o.day,
+            title: find_day(o.year, o.day).map_or("", |d| d.title).to_string(),
+            part: o.part,
+            file: o.file.clone(),
+            answer: answered.then(|| o.answer.to_string()),
+            answer_type: answered.then(|| o.answer.kind()),
+            read_ns: o.read.as_nanos() as u64,
+            parse_ns: o.parse.as_nanos() as u64,
+            solve_ns: o.duration.as_nanos() as u64,
+            status,
+            expected,
+            error,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+// where the answer differs from the expected one: a caret under the first different char,
+// or the different lines for multi-line answers
+pub fn pp_diff(expected: &str, answer: &str) -> String {
+    if !expected.contains('\n') && !answer.contains('\n') {
+        let col = expected.chars().zip(answer.chars()).take_while(|(e, a)| e == a).count();
+        let margin = "    got     : ".len();
+        return format!(
+            "    expected: {}\n    got     : {}\n{}^\n",
+            expected,
+            answer,
+            " ".repeat(margin + col)
+        );
+    }
+    let (expected, answer) = (expected.lines().collect::<Vec<_>>(), answer.lines().collect::<Vec<_>>());
+    let mut s = String::new();
+    for i in 0..expected.len().max(answer.len()) {
+        match (expected.get(i), answer.get(i)) {
+            (Some(e), Some(a)) if e == a => s += &format!("      {}\n", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    s += &format!("    - {}\n", e);
+                }
+                if let Some(a) = a {
+                    s += &format!("    + {}\n", a);
+                }
+            }
+        }
+    }
+    s
+}
+
+//--------------------------------------------------------------------------------
+// parallel run
+//--------------------------------------------------------------------------------
+
+// the jobs run on `nb_threads` workers. a job is the unit of work: the parts of one file share
+// its parsed input, so they stay on the same thread. the outcomes come back in the jobs order,
+// with the diagnostics of the files that didn't parse and the sum of the jobs wall times (not cpu time)
+pub fn run_parallel(
+    jobs: &[(&Day, Job)],
+    answers: &Answers,
+    timeout: Option<Duration>,
+    nb_threads: usize,
+) -> (Vec<Outcome>, Vec<String>, Duration) {
+    let next = AtomicUsize::new(0);
+    let done = Mutex::new(vec![]);
+    thread::scope(|s| {
+        for _ in 0..nb_threads.max(1) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((day, job)) = jobs.get(i) else {
+                    break;
+                };
+                let (res, duration) = time_it(|| solve_job(day, job, answers, timeout));
+                done.lock().unwrap().push((i, res, duration));
+            });
+        }
+    });
+
+    let mut done = done.into_inner().unwrap();
+    done.sort_by_key(|(i, _, _)| *i);
+    let mut outcomes = vec![];
+    let mut diagnostics = vec![];
+    let mut jobs_time = Duration::ZERO;
+    for (_, (job_outcomes, diagnostic), duration) in done {
+        outcomes.extend(job_outcomes);
+        diagnostics.extend(diagnostic);
+        jobs_time += duration;
+    }
+    (outcomes, diagnostics, jobs_time)
+}
+
+// one line per part, sorted by day and part
+pub fn pp_summary(outcomes: &[Outcome], wall: Duration, jobs_time: Duration) {
+    let mut rows = outcomes
+        .iter()
+        .map(|o| {
+            let title = find_day(o.year, o.day).map_or("", |d| d.title);
+            [
+                format!("day{:02}", o.day),
+                title.to_string(),
+                format!("p{}", o.part),
+                o.file.clone(),
+                o.answer.to_string(),
+                fmt_duration(o.duration),
+                o.status.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by(|a, b| (&a[0], &a[2]).cmp(&(&b[0], &b[2])));
+
+    let header = ["day", "title", "part", "file", "answer", "time", "status"].map(String::from);
+    let widths = (0..header.len())
+        .map(|c| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[c].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for row in [&header].into_iter().chain(rows.iter()) {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>();
+        println!("{}", cells.join("  ").trim_end());
+    }
+    println!(
+        "\nwall {}, sum of job times {} ({:.1}x)",
+        fmt_duration(wall),
+        fmt_duration(jobs_time),
+        jobs_time.as_secs_f64() / wall.as_secs_f64().max(1e-9)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // slow to parse, and p1 panics
+    struct Broken;
+
+    impl Solution for Broken {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 99;
+        const TITLE: &'static str = "broken";
+        const RUNS: &'static [Run] = &[Run::new(1, "data/2024/01_sample.txt")];
+
+        type Input = bool;
+
+        fn parse(_: &str) -> Result<bool, ParseError> {
+            thread::sleep(Duration::from_millis(50));
+            Ok(true)
+        }
+
+        fn p1(panics: &bool, _: &Params) -> impl Into<Answer> {
+            if *panics {
+                panic!("boom");
+            }
+            0
+        }
+    }
+
+    #[test]
+    fn test() {
+        let answers = Answers::load(&answers_file(2024)).unwrap();
+        let mut jobs = vec![];
+        for d in [1, 2, 3].into_iter().filter_map(|day| find_day(2024, day)) {
+            jobs.extend(plan_verify(d, &answers).unwrap().into_iter().map(|job| (d, job)));
+        }
+        // same outcomes and order as one job after the other
+        let (outcomes, diagnostics, _) = run_parallel(&jobs, &answers, None, 4);
+        let sequential = jobs
+            .iter()
+            .flat_map(|(d, job)| solve_job(d, job, &answers, Some(Duration::from_secs(60))).0)
+            .collect::<Vec<_>>();
+        let key = |o: &Outcome| (o.day, o.part, o.file.clone());
+        assert_eq!(
+            outcomes.iter().map(key).collect::<Vec<_>>(),
+            sequential.iter().map(key).collect::<Vec<_>>()
+        );
+        assert!(diagnostics.is_empty());
+        assert!(outcomes.iter().all(|o| o.status == Status::Pass));
+
+        let record = Record::from_outcome(&outcomes[0]);
+        assert_eq!((record.day, record.part, record.status), (1, 1, "PASS"));
+        assert_eq!(record.answer_type, Some("number"));
+        assert!(record.to_json().starts_with(r#"{"year":2024,"day":1,"title":"#));
+
+        assert_eq!(
+            pp_diff("0,3,5,4", "0,3,7,4"),
+            "    expected: 0,3,5,4\n    got     : 0,3,7,4\n                  ^\n"
+        );
+        assert_eq!(pp_diff("a\nb", "a\nc"), "      a\n    - b\n    + c\n");
+
+        // day06 p2 takes seconds: it times out, and its thread stops at the next `cancelled` check
+        let day06 = find_day(2024, 6).unwrap();
+        let job = plan(day06, Some(2), None, &[]).unwrap().remove(0);
+        let (outcomes, _) = solve_job(day06, &job, &answers, Some(Duration::from_millis(1)));
+        assert_eq!(outcomes[0].status, Status::Timeout(Duration::from_millis(1)));
+        assert_eq!(Record::from_outcome(&outcomes[0]).status, "TIMEOUT");
+
+        // a slow parse times out too, and a panic is an error with or without a timeout
+        let broken = Day::of::<Broken>();
+        let job = plan(&broken, None, None, &[]).unwrap().remove(0);
+        let (outcomes, _) = solve_job(&broken, &job, &answers, Some(Duration::from_millis(1)));
+        assert_eq!(outcomes[0].status, Status::Timeout(Duration::from_millis(1)));
+        for timeout in [None, Some(Duration::from_secs(5))] {
+            let (outcomes, _) = solve_job(&broken, &job, &answers, timeout);
+            assert_eq!(outcomes[0].status, Status::Error(String::from("panicked: boom")));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::{fs, path::Path};
+
+use regex::Regex;
+
+use crate::answers::Expected;
+
+//--------------------------------------------------------------------------------
+// html
+//--------------------------------------------------------------------------------
+
+// the puzzle page has one article per part. the examples are `<pre><code>` blocks, and the
+// example answer is the last emphasized code of the article (`<code><em>11</em></code>`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartDesc {
+    pub samples: Vec<String>,
+    pub answer: Option<String>,
+}
+
+fn unescape(s: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(s, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn parse_html(html: &str) -> Vec<PartDesc> {
+    let article = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer = Regex::new(r"<code><em>([^<]*)</em></code>|<em><code>([^<]*)</code></em>").unwrap();
+    article
+        .captures_iter(html)
+        .map(|c| {
+            let body = c.get(1).unwrap().as_str();
+            PartDesc {
+                samples: pre.captures_iter(body).map(|c| unescape(&c[1])).collect(),
+                answer: answer
+                    .captures_iter(body)
+                    .last()
+                    .map(|c| unescape(c.get(1).or(c.get(2)).unwrap().as_str())),
+            }
+        })
+        .collect()
+}
+
+//--------------------------------------------------------------------------------
+// samples
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sample {
+    Written(String),
+    Existing(String), // the same content is already in data/, maybe under a hand-made name
+}
+
+impl Sample {
+    pub fn file(&self) -> &str {
+        match self {
+            Sample::Written(file) | Sample::Existing(file) => file,
+        }
+    }
+}
+
+// the files of the day already in data_dir, with their content
+fn day_files(data_dir: &str, day: u32) -> Vec<(String, String)> {
+    let prefix = format!("{:02}_", day);
+    let mut files: Vec<(String, String)> = fs::read_dir(data_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .filter_map(|entry| {
+            let file = format!("{}/{}", data_dir, entry.file_name().to_string_lossy());
+            fs::read_to_string(&file).ok().map(|content| (file, content))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+// the example blocks go to NN_sample.txt, NN_sample2.txt... unless they are already there
+pub fn write_samples(data_dir: &str, day: u32, samples: &[String]) -> Result<Vec<Sample>, String> {
+    let mut res = vec![];
+    for sample in samples {
+        let existing = day_files(data_dir, day);
+        if let Some((file, _)) = existing
+            .iter()
+            .find(|(_, content)| content.trim_end() == sample.trim_end())
+        {
+            res.push(Sample::Existing(file.clone()));
+            continue;
+        }
+        let file = (1..)
+            .map(|i| match i {
+                1 => format!("{}/{:02}_sample.txt", data_dir, day),
+                _ => format!("{}/{:02}_sample{}.txt", data_dir, day, i),
+            })
+            .find(|file| !Path::new(file).exists())
+            .unwrap();
+        fs::write(&file, sample).map_err(|err| format!("cannot write {}: {}", file, err))?;
+        res.push(Sample::Written(file));
+    }
+    Ok(res)
+}
+
+// the expected answers of the example of each part: part 1 uses the first block of its article,
+// part 2 too, unless its article comes with a new example
+pub fn expected_answers(parts: &[PartDesc], samples: &[Sample]) -> Vec<(String, Expected)> {
+    let mut res: Vec<(String, Expected)> = vec![];
+    let mut first_of_part = 0;
+    let mut sample_file = None;
+    for (i, part) in parts.iter().take(2).enumerate() {
+        if !part.samples.is_empty() {
+            sample_file = samples.get(first_of_part).map(|s| s.file().to_string());
+        }
+        first_of_part += part.samples.len();
+        let (Some(file), Some(answer)) = (&sample_file, &part.answer) else {
+            continue;
+        };
+        let pos = match res.iter().position(|(f, _)| f == file) {
+            Some(pos) => pos,
+            None => {
+                res.push((file.clone(), Expected::default()));
+                res.len() - 1
+            }
+        };
+        match i {
+            0 => res[pos].1.p1 = Some(answer.clone()),
+            _ => res[pos].1.p2 = Some(answer.clone()),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Test ---</h2><p>For example:</p>
+<pre><code>a &lt; b
+<em>c</em> &amp; d
+</code></pre>
+<p>Here, <code><em>x</em></code> is not the answer, the total is <code><em>161</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>e
+</code></pre>
+<p>This time, the result is <em><code>48</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test() {
+        let parts = parse_html(HTML);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].samples, vec!["a < b\nc & d\n"]);
+        assert_eq!(parts[0].answer.as_deref(), Some("161"));
+        assert_eq!(parts[1].answer.as_deref(), Some("48"));
+
+        let data_dir = std::env::temp_dir().join(format!("aoc24_samples_{}", std::process::id()));
+        fs::create_dir_all(&data_dir).unwrap();
+        let data_dir = data_dir.to_string_lossy().to_string();
+        fs::write(format!("{}/03_sample1b.txt", data_dir), "e").unwrap();
+
+        let all_samples = parts.iter().flat_map(|p| p.samples.clone()).collect::<Vec<_>>();
+        let samples = write_samples(&data_dir, 3, &all_samples).unwrap();
+        assert_eq!(samples[0], Sample::Written(format!("{}/03_sample.txt", data_dir)));
+        assert_eq!(samples[1], Sample::Existing(format!("{}/03_sample1b.txt", data_dir)));
+        // nothing new the second time
+        assert!(write_samples(&data_dir, 3, &all_samples)
+            .unwrap()
+            .iter()
+            .all(|s| matches!(s, Sample::Existing(_))));
+
+        let expected = expected_answers(&parts, &samples);
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected[0].1.part(1), Some("161"));
+        assert_eq!(expected[1].1.part(2), Some("48"));
+
+        fs::remove_dir_all(&data_dir).unwrap();
+    }
+}
@@ -0,0 +1,245 @@
+use std::{fs, path::Path};
+
+use crate::answers::{self, Expected};
+
+//--------------------------------------------------------------------------------
+// templates
+//--------------------------------------------------------------------------------
+
+pub fn day_template(year: u32, day: u32, title: &str) -> String {
+    let template = r#"use crate::solution::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+fn p1(input: &str) -> i64 {
+    input.lines().count() as i64
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+fn p2(input: &str) -> i64 {
+    input.lines().count() as i64
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day__NN__;
+
+impl Solution for Day__NN__ {
+    const YEAR: u32 = __YEAR__;
+    const DAY: u32 = __DAY__;
+    const TITLE: &'static str = "__TITLE__";
+    const RUNS: &'static [Run] = &[Run::new(1, "data/__YEAR__/__NN___input.txt"), Run::new(2, "data/__YEAR__/__NN___input.txt")];
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn p1(input: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(input)
+    }
+
+    fn p2(input: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day__NN__>(1, "data/__YEAR__/__NN___sample.txt"), "0");
+        assert_eq!(solve_file::<Day__NN__>(2, "data/__YEAR__/__NN___sample.txt"), "0");
+    }
+}
+"#;
+    template
+        .replace("__NN__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string())
+        .replace("__TITLE__", &title.replace('\\', "\\\\").replace('"', "\\\""))
+        .replace("__YEAR__", &year.to_string())
+}
+
+pub fn year_template(year: u32) -> String {
+    format!(
+        "// the days of {}. `new` adds the module and its registry entry, keep them in day order\n\n\
+         use crate::solution::Day;\n\n\
+         pub const DAYS: &[Day] = &[];\n",
+        year
+    )
+}
+
+//--------------------------------------------------------------------------------
+// registration
+//--------------------------------------------------------------------------------
+
+// insert `line` in order among the lines matching `is_same`, or at `fallback` if there are none
+fn insert_sorted(lines: &mut Vec<String>, line: String, is_same: impl Fn(&str) -> bool, fallback: usize) {
+    let same = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| is_same(l))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let pos = same
+        .iter()
+        .find(|&&i| lines[i].trim() > line.trim())
+        .copied()
+        .or(same.last().map(|i| i + 1))
+        .unwrap_or(fallback);
+    lines.insert(pos, line);
+}
+
+// add `pub mod dayNN;` and its entry in DAYS to the year's mod.rs
+pub fn register_day(mod_rs: &str, day: u32) -> String {
+    let mod_rs = mod_rs.replace("pub const DAYS: &[Day] = &[];", "pub const DAYS: &[Day] = &[\n];");
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+
+    let days_start = lines.iter().position(|l| l.starts_with("pub const DAYS")).unwrap();
+    let is_mod = |l: &str| l.starts_with("pub mod day");
+    let first_mod = lines.iter().position(|l| is_mod(l));
+    if first_mod.is_none() {
+        lines.insert(days_start, String::new());
+    }
+    insert_sorted(&mut lines, format!("pub mod day{:02};", day), is_mod, days_start);
+
+    let days_start = lines.iter().position(|l| l.starts_with("pub const DAYS")).unwrap();
+    let days_end = days_start + lines[days_start..].iter().position(|l| l == "];").unwrap();
+    let entry = format!("    Day::of::<day{:02}::Day{:02}>(),", day, day);
+    insert_sorted(&mut lines, entry, |l| l.starts_with("    Day::of::<day"), days_end);
+
+    lines.join("\n") + "\n"
+}
+
+// add `pub mod yYYYY;` and its entry in YEARS to lib.rs
+pub fn register_year(lib_rs: &str, year: u32) -> String {
+    let mut lines: Vec<String> = lib_rs.lines().map(String::from).collect();
+    let is_year = |l: &str| l.starts_with("pub mod y") && l[9..].starts_with(|c: char| c.is_ascii_digit());
+    let fallback = lines
+        .iter()
+        .rposition(|l| l.starts_with("pub mod "))
+        .map_or(0, |i| i + 1);
+    insert_sorted(&mut lines, format!("pub mod y{};", year), is_year, fallback);
+
+    // the entries are 4 lines long (the way rustfmt writes them), sorted by year. rustfmt puts a
+    // lone entry on the `YEARS` line, so spread it first
+    let years_start = lines.iter().position(|l| l.starts_with("pub const YEARS")).unwrap();
+    if let Some(start) = lines[years_start].strip_suffix("Year {").map(String::from) {
+        let end = years_start + lines[years_start..].iter().position(|l| l == "}];").unwrap();
+        lines[years_start] = format!("{}\n    Year {{", start);
+        for line in &mut lines[years_start + 1..end] {
+            *line = format!("    {}", line);
+        }
+        lines[end] = String::from("    },\n];");
+        lines = lines.join("\n").lines().map(String::from).collect();
+    }
+    let years_end = years_start + lines[years_start..].iter().position(|l| l == "];").unwrap();
+    let pos = (years_start + 1..years_end)
+        .filter(|&i| lines[i] == "    Year {")
+        .find(|&i| lines[i + 1].trim() > format!("year: {},", year).as_str())
+        .unwrap_or(years_end);
+    let entry = [
+        String::from("    Year {"),
+        format!("        year: {},", year),
+        format!("        days: y{}::DAYS,", year),
+        String::from("    },"),
+    ];
+    lines.splice(pos..pos, entry);
+    lines.join("\n") + "\n"
+}
+
+//--------------------------------------------------------------------------------
+// new day
+//--------------------------------------------------------------------------------
+
+// everything a new day needs: its module (and its year's, the first time), empty inputs, and
+// manifest stubs to fill with the answers. returns the files created or changed
+pub fn new_day(src_dir: &str, year: u32, day: u32, title: &str) -> Result<Vec<String>, String> {
+    let write =
+        |file: &str, content: &str| fs::write(file, content).map_err(|err| format!("cannot write {}: {}", file, err));
+    let read = |file: &str| fs::read_to_string(file).map_err(|err| format!("cannot read {}: {}", file, err));
+
+    let year_dir = format!("{}/y{}", src_dir, year);
+    let day_file = format!("{}/day{:02}.rs", year_dir, day);
+    if Path::new(&day_file).exists() {
+        return Err(format!("{} already exists", day_file));
+    }
+    let mut changed = vec![];
+
+    let mod_file = format!("{}/mod.rs", year_dir);
+    if !Path::new(&mod_file).exists() {
+        fs::create_dir_all(&year_dir).map_err(|err| format!("cannot create {}: {}", year_dir, err))?;
+        write(&mod_file, &year_template(year))?;
+        let lib_file = format!("{}/lib.rs", src_dir);
+        write(&lib_file, &register_year(&read(&lib_file)?, year))?;
+        changed.push(lib_file);
+    }
+    write(&day_file, &day_template(year, day, title))?;
+    changed.push(day_file);
+    write(&mod_file, &register_day(&read(&mod_file)?, day))?;
+    changed.push(mod_file);
+
+    let data_dir = crate::data_dir(year);
+    fs::create_dir_all(&data_dir).map_err(|err| format!("cannot create {}: {}", data_dir, err))?;
+    let answers_file = answers::answers_file(year);
+    let mut manifest = fs::read_to_string(&answers_file).unwrap_or(answers::manifest_header(year));
+    for name in ["sample", "input"] {
+        let file = format!("{}/{:02}_{}.txt", data_dir, day, name);
+        if !Path::new(&file).exists() {
+            write(&file, "")?;
+            changed.push(file.clone());
+        }
+        manifest = answers::add_entry(&manifest, day, &file, &Expected::default());
+    }
+    write(&answers_file, &manifest)?;
+    changed.push(answers_file);
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let template = day_template(2025, 7, "A \"quoted\" title");
+        assert!(template.contains("pub struct Day07;"));
+        assert!(template.contains("const DAY: u32 = 7;"));
+        assert!(template.contains(r#"const TITLE: &'static str = "A \"quoted\" title";"#));
+        assert!(template.contains(r#"solve_file::<Day07>(1, "data/2025/07_sample.txt")"#));
+
+        // first day of a year, then one before it
+        let mod_rs = register_day(&year_template(2025), 7);
+        let mod_rs = register_day(&mod_rs, 3);
+        assert!(mod_rs.ends_with(
+            "use crate::solution::Day;\n\npub mod day03;\npub mod day07;\n\npub const DAYS: &[Day] = &[\n    \
+             Day::of::<day03::Day03>(),\n    Day::of::<day07::Day07>(),\n];\n"
+        ));
+
+        // the real 2024 module: day 26 would go last
+        let mod_rs = register_day(&fs::read_to_string("src/y2024/mod.rs").unwrap(), 26);
+        assert!(mod_rs.contains("pub mod day25;\npub mod day26;\n"));
+        assert!(mod_rs.contains("    Day::of::<day25::Day25>(),\n    Day::of::<day26::Day26>(),\n];"));
+
+        // a lone year, the way rustfmt writes it
+        let lib_rs = "pub mod utils;\npub mod y2024;\n\npub const YEARS: &[Year] = &[Year {\n    year: 2024,\n    \
+                      days: y2024::DAYS,\n}];\n";
+        let lib_rs = register_year(lib_rs, 2025);
+        assert!(lib_rs.contains("pub mod y2024;\npub mod y2025;\n"));
+        assert!(lib_rs.contains(
+            "        days: y2024::DAYS,\n    },\n    Year {\n        year: 2025,\n        days: y2025::DAYS,\n    },\n];"
+        ));
+        let lib_rs = register_year(&lib_rs, 2023);
+        assert!(lib_rs.contains("pub const YEARS: &[Year] = &[\n    Year {\n        year: 2023,\n"));
+    }
+}
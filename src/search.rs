@@ -0,0 +1,371 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+// searches over any kind of state (a position, a position and a direction...). the graph is only
+// known through a closure giving the next states of a state, with the cost of each move for the
+// weighted searches (costs must be > 0)
+
+//--------------------------------------------------------------------------------
+// bfs
+//--------------------------------------------------------------------------------
+
+// the number of moves from start to every reachable state
+pub fn bfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut dist = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let d = dist[&state];
+        for nx in neighbors(&state) {
+            if !dist.contains_key(&nx) {
+                dist.insert(nx.clone(), d + 1);
+                queue.push_back(nx);
+            }
+        }
+    }
+    dist
+}
+
+// the closest state reaching the goal, and its number of moves
+pub fn bfs_to<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I, goal: impl Fn(&S) -> bool) -> Option<(S, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, d)) = queue.pop_front() {
+        if goal(&state) {
+            return Some((state, d));
+        }
+        for nx in neighbors(&state) {
+            if seen.insert(nx.clone()) {
+                queue.push_back((nx, d + 1));
+            }
+        }
+    }
+    None
+}
+
+//--------------------------------------------------------------------------------
+// dijkstra / a*
+//--------------------------------------------------------------------------------
+
+// in the heap, the lowest priority first (BinaryHeap is a max-heap)
+struct Queued<S> {
+    priority: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// what a best-first search found: the goal reached (if any), the best cost found for each state
+// and the predecessor of each state
+struct Found<S> {
+    reached: Option<(S, usize)>,
+    costs: HashMap<S, usize>,
+    preds: HashMap<S, S>,
+}
+
+// dijkstra when the heuristic is 0. the heuristic must never overestimate the cost left
+fn best_first<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> usize,
+    goal: impl Fn(&S) -> bool,
+) -> Found<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut preds = HashMap::new();
+    let mut heap = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }]);
+    while let Some(Queued { cost, state, .. }) = heap.pop() {
+        if cost > costs[&state] {
+            // a better way to this state was found after this one was queued
+            continue;
+        }
+        if goal(&state) {
+            return Found {
+                reached: Some((state, cost)),
+                costs,
+                preds,
+            };
+        }
+        for (nx, step) in neighbors(&state) {
+            let nx_cost = cost + step;
+            if costs.get(&nx).is_none_or(|&c| nx_cost < c) {
+                costs.insert(nx.clone(), nx_cost);
+                preds.insert(nx.clone(), state.clone());
+                heap.push(Queued {
+                    priority: nx_cost + heuristic(&nx),
+                    cost: nx_cost,
+                    state: nx,
+                });
+            }
+        }
+    }
+    Found {
+        reached: None,
+        costs,
+        preds,
+    }
+}
+
+// the lowest cost from start to every reachable state
+pub fn dijkstra<S, I>(start: S, neighbors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(start, neighbors, |_| 0, |_| false).costs
+}
+
+// the cheapest state reaching the goal, and its cost
+pub fn dijkstra_to<S, I>(start: S, neighbors: impl FnMut(&S) -> I, goal: impl Fn(&S) -> bool) -> Option<(S, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(start, neighbors, |_| 0, goal).reached
+}
+
+// the cheapest path to the goal (start first), and its cost
+pub fn astar<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> usize,
+    goal: impl Fn(&S) -> bool,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let Found { reached, preds, .. } = best_first(start, neighbors, heuristic, goal);
+    let (end, cost) = reached?;
+    let mut path = vec![end];
+    while let Some(pred) = preds.get(path.last().unwrap()) {
+        path.push(pred.clone());
+    }
+    path.reverse();
+    Some((path, cost))
+}
+
+//--------------------------------------------------------------------------------
+// all shortest paths
+//--------------------------------------------------------------------------------
+
+// the lowest cost of every reachable state, with all its predecessors on a cheapest path: a dag
+// holding every optimal path from start
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    pub start: S,
+    pub costs: HashMap<S, usize>,
+    pub preds: HashMap<S, Vec<S>>,
+}
+
+pub fn all_shortest_paths<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut preds: HashMap<S, Vec<S>> = HashMap::new();
+    let mut done = HashSet::new();
+    let mut heap = BinaryHeap::from([Queued {
+        priority: 0,
+        cost: 0,
+        state: start.clone(),
+    }]);
+    while let Some(Queued { cost, state, .. }) = heap.pop() {
+        if !done.insert(state.clone()) {
+            continue;
+        }
+        for (nx, step) in neighbors(&state) {
+            let nx_cost = cost + step;
+            match costs.get(&nx).map(|&c| nx_cost.cmp(&c)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => preds.entry(nx).or_default().push(state.clone()),
+                _ => {
+                    costs.insert(nx.clone(), nx_cost);
+                    preds.insert(nx.clone(), vec![state.clone()]);
+                    heap.push(Queued {
+                        priority: nx_cost,
+                        cost: nx_cost,
+                        state: nx,
+                    });
+                }
+            }
+        }
+    }
+    Paths { start, costs, preds }
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.costs.get(state).copied()
+    }
+
+    // every state on at least one optimal path from start to one of the ends
+    pub fn on_paths(&self, ends: &[S]) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut to_explore = ends
+            .iter()
+            .filter(|end| self.costs.contains_key(end))
+            .cloned()
+            .collect::<Vec<_>>();
+        while let Some(state) = to_explore.pop() {
+            if seen.insert(state.clone()) {
+                to_explore.extend(self.preds.get(&state).into_iter().flatten().cloned());
+            }
+        }
+        seen
+    }
+
+    // the number of optimal paths from start to end
+    pub fn nb_paths(&self, end: &S) -> usize {
+        let mut states = self.on_paths(std::slice::from_ref(end)).into_iter().collect::<Vec<_>>();
+        states.sort_by_key(|state| self.costs[state]);
+        let mut nb_paths = HashMap::new();
+        for state in states {
+            let n = match state == self.start {
+                true => 1,
+                false => self.preds[&state].iter().map(|pred| nb_paths[pred]).sum(),
+            };
+            nb_paths.insert(state, n);
+        }
+        nb_paths.get(end).copied().unwrap_or(0)
+    }
+
+    // one of the optimal paths from start to end (start first)
+    pub fn path(&self, end: &S) -> Option<Vec<S>> {
+        self.costs.get(end)?;
+        let mut path = vec![end.clone()];
+        while let Some(pred) = self.preds.get(path.last().unwrap()).and_then(|preds| preds.first()) {
+            path.push(pred.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // every optimal path from start to end (start first), one at a time
+    pub fn all_paths(&self, end: &S) -> AllPaths<'_, S> {
+        let stack = match self.costs.contains_key(end) {
+            true => vec![(end.clone(), 0)],
+            false => vec![],
+        };
+        AllPaths { paths: self, stack }
+    }
+}
+
+// a dfs in the dag, from the end back to the start
+pub struct AllPaths<'a, S> {
+    paths: &'a Paths<S>,
+    // the states walked back from the end, each with the index of its next pred to try
+    stack: Vec<(S, usize)>,
+}
+
+impl<S: Clone + Eq + Hash> Iterator for AllPaths<'_, S> {
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Vec<S>> {
+        loop {
+            let (state, i) = self.stack.last_mut()?;
+            if *state == self.paths.start {
+                let path = self.stack.iter().rev().map(|(state, _)| state.clone()).collect();
+                self.stack.pop();
+                return Some(path);
+            }
+            match self.paths.preds.get(state).and_then(|preds| preds.get(*i)) {
+                Some(pred) => {
+                    *i += 1;
+                    let pred = pred.clone();
+                    self.stack.push((pred, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::identity;
+
+    use super::*;
+    use crate::utils::{Matrix, V2};
+
+    #[test]
+    fn test() {
+        let maze = Matrix::from_str("S..#\n.#..\n...E", identity);
+        let start = maze.find_first('S').unwrap();
+        let end = maze.find_first('E').unwrap();
+        let free = |pos: &V2| {
+            maze.neighbors(pos)
+                .into_iter()
+                .filter(|nx| maze.get_ref(nx) != Some(&'#'))
+                .collect::<Vec<_>>()
+        };
+        let weighted = |pos: &V2| free(pos).into_iter().map(|nx| (nx, 1)).collect::<Vec<_>>();
+
+        let dist = bfs(start, free);
+        assert_eq!(dist[&end], 5);
+        assert_eq!(dist.len(), 10);
+        assert_eq!(bfs_to(start, free, |pos| *pos == end), Some((end, 5)));
+        assert_eq!(bfs_to(start, free, |pos| pos.x == 9), None);
+
+        assert_eq!(dijkstra(start, weighted)[&end], 5);
+        assert_eq!(dijkstra_to(start, weighted, |pos| *pos == end), Some((end, 5)));
+        let manhattan = |pos: &V2| pos.manhattan(&end) as usize;
+        let (path, cost) = astar(start, weighted, manhattan, |pos| *pos == end).unwrap();
+        assert_eq!((path.len(), cost), (6, 5));
+        assert_eq!((path[0], path[5]), (start, end));
+
+        // 3 ways around the wall in the middle, 2 above it and 1 below it, going through every free cell
+        let paths = all_shortest_paths(start, weighted);
+        assert_eq!(paths.cost(&end), Some(5));
+        assert_eq!(paths.nb_paths(&end), 3);
+        assert_eq!(paths.on_paths(&[end]).len(), 10);
+        assert_eq!(paths.path(&end).unwrap().len(), 6);
+        assert_eq!(paths.nb_paths(&V2::new(9, 9)), 0);
+        let all = paths.all_paths(&end).collect::<HashSet<_>>();
+        assert_eq!(all.len(), 3);
+        assert!(all
+            .iter()
+            .all(|path| path.len() == 6 && path[0] == start && path[5] == end));
+        assert_eq!(paths.all_paths(&start).collect::<Vec<_>>(), vec![vec![start]]);
+        assert_eq!(paths.all_paths(&V2::new(9, 9)).count(), 0);
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    cell::RefCell,
+    fmt, fs,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+pub use crate::parse::ParseError;
+use crate::utils::V2;
+
+//--------------------------------------------------------------------------------
+// answers
+//--------------------------------------------------------------------------------
+
+// what a part returns. its Display is the exact string the site expects, so the runner, the
+// manifest and `submit` all agree on it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Num(i128),    // any integer
+    Text(String), // a password, a list of names...
+    Pos(V2),      // coordinates, as `x,y`
+    None,         // no answer (day 25 has no part 2, a part that failed...)
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Num(_) => "number",
+            Answer::Text(_) => "string",
+            Answer::Pos(_) => "position",
+            Answer::None => "none",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Num(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Pos(pos) => write!(f, "{},{}", pos.x, pos.y),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Num(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<V2> for Answer {
+    fn from(pos: V2) -> Answer {
+        Answer::Pos(pos)
+    }
+}
+
+// a part that can find nothing (no path to the end...)
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Answer {
+        answer.map_or(Answer::None, Into::into)
+    }
+}
+
+//--------------------------------------------------------------------------------
+// params
+//--------------------------------------------------------------------------------
+
+// a parameter a day declares (grid size, number of steps...), with its default value and a check
+// that a value can be read as the type the day will `get`
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub check: fn(&str) -> Result<(), String>,
+}
+
+impl Param {
+    pub const fn of<T: FromStr>(name: &'static str, default: &'static str) -> Param {
+        Param {
+            name,
+            default,
+            check: check_value::<T>,
+        }
+    }
+}
+
+fn check_value<T: FromStr>(value: &str) -> Result<(), String> {
+    match value.parse::<T>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("expected a {}", std::any::type_name::<T>())),
+    }
+}
+
+// the values, checked when set, so a bad one is an error before any part runs (not a panic in `get`)
+#[derive(Clone, Default)]
+pub struct Params(Vec<(&'static Param, String)>);
+
+impl Params {
+    // the defaults, unchecked (see `Day::default_params`)
+    pub fn new(declared: &'static [Param]) -> Params {
+        Params(declared.iter().map(|p| (p, p.default.to_string())).collect())
+    }
+
+    // only declared params can be set, to a value of their type
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match self.0.iter_mut().find(|(p, _)| p.name == name) {
+            Some((p, v)) => {
+                (p.check)(value).map_err(|err| format!("invalid value '{}' for param '{}': {}", value, name, err))?;
+                *v = value.to_string();
+                Ok(())
+            }
+            None => Err(format!("unknown param '{}'", name)),
+        }
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> T {
+        let (_, value) = self
+            .0
+            .iter()
+            .find(|(p, _)| p.name == name)
+            .unwrap_or_else(|| panic!("param '{}' is not declared", name));
+        value
+            .parse::<T>()
+            .unwrap_or_else(|_| panic!("invalid value '{}' for param '{}'", value, name))
+    }
+}
+
+//--------------------------------------------------------------------------------
+// runs
+//--------------------------------------------------------------------------------
+
+// one p1/p2 call against one input file, with the params that differ from the defaults
+pub struct Run {
+    pub part: u8,
+    pub file: &'static str,
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl Run {
+    pub const fn new(part: u8, file: &'static str) -> Run {
+        Run {
+            part,
+            file,
+            params: &[],
+        }
+    }
+
+    pub const fn with(part: u8, file: &'static str, params: &'static [(&'static str, &'static str)]) -> Run {
+        Run { part, file, params }
+    }
+}
+
+//--------------------------------------------------------------------------------
+// solution
+//--------------------------------------------------------------------------------
+
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+    const TITLE: &'static str;
+    const PARAMS: &'static [Param] = &[];
+    const RUNS: &'static [Run];
+
+    // sent to the thread solving a part (see the runner timeout)
+    type Input: Send + Sync;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn p1(input: &Self::Input, params: &Params) -> impl Into<Answer>;
+
+    // only day 25 has no part 2, and it doesn't declare any run for it
+    fn p2(_input: &Self::Input, _params: &Params) -> impl Into<Answer> {
+        Answer::None
+    }
+}
+
+// type erased view of a parsed input, so we can keep all the days in one registry
+pub trait Solver: Send + Sync {
+    fn solve(&self, part: u8, params: &Params) -> Answer;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Solver for Parsed<S> {
+    fn solve(&self, part: u8, params: &Params) -> Answer {
+        match part {
+            1 => S::p1(&self.0, params).into(),
+            _ => S::p2(&self.0, params).into(),
+        }
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solver>, ParseError> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+//--------------------------------------------------------------------------------
+// registry
+//--------------------------------------------------------------------------------
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub params: &'static [Param],
+    pub runs: &'static [Run],
+    pub parse: fn(&str) -> Result<Box<dyn Solver>, ParseError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            params: S::PARAMS,
+            runs: S::RUNS,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("day{:02}: {}", self.day, self.title)
+    }
+
+    pub fn has_part(&self, part: u8) -> bool {
+        self.runs.iter().any(|run| run.part == part)
+    }
+
+    // the declared defaults, checked like the values given on the command line
+    pub fn default_params(&self) -> Result<Params, String> {
+        let mut params = Params::new(self.params);
+        for p in self.params {
+            params.set(p.name, p.default)?;
+        }
+        Ok(params)
+    }
+
+    // defaults, overridden by what the run declares for its input
+    pub fn params_for(&self, run: &Run) -> Result<Params, String> {
+        let mut params = self.default_params()?;
+        for (name, value) in run.params {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+
+    // the params of the run declared for that part and file, or the defaults
+    pub fn params_for_file(&self, part: u8, file: &str) -> Result<Params, String> {
+        match self.run_for(part, file) {
+            Some(run) => self.params_for(run),
+            None => self.default_params(),
+        }
+    }
+
+    // the declared run for this part and file, or the last one of the part (usually the real input)
+    pub fn run_for(&self, part: u8, file: &str) -> Option<&'static Run> {
+        let mut runs = self.runs.iter().filter(|run| run.part == part);
+        runs.clone().find(|run| run.file == file).or(runs.next_back())
+    }
+}
+
+//--------------------------------------------------------------------------------
+// cancellation
+//--------------------------------------------------------------------------------
+
+thread_local! {
+    static CANCEL: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+// set by the runner on the thread solving a part, raised when it gives up on it
+pub fn set_cancel_flag(flag: Arc<AtomicBool>) {
+    CANCEL.with(|cancel| *cancel.borrow_mut() = Some(flag));
+}
+
+// threads can't be killed: long loops check this and return early once the part timed out
+// (whatever they return is thrown away)
+pub fn cancelled() -> bool {
+    CANCEL.with(|cancel| {
+        cancel
+            .borrow()
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    })
+}
+
+//--------------------------------------------------------------------------------
+// inputs
+//--------------------------------------------------------------------------------
+
+pub fn read_input(file: &str) -> String {
+    let input = fs::read_to_string(file).unwrap_or_else(|_| panic!("cannot read input file {}", file));
+    input.trim_end().to_string()
+}
+
+// parse the file and solve one part, with the params declared for that file (used by the tests)
+pub fn solve_file<S: Solution + 'static>(part: u8, file: &str) -> String {
+    let day = Day::of::<S>();
+    let params = day
+        .params_for_file(part, file)
+        .unwrap_or_else(|err| panic!("{}: {}", day.name(), err));
+    let content = read_input(file);
+    let input = S::parse(&content).unwrap_or_else(|err| panic!("{}", err.locate(file, &content)));
+    Parsed::<S>(input).solve(part, &params).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(Answer::from(-12i64).to_string(), "-12");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(V2::new(28, 56)).to_string(), "28,56");
+        assert_eq!(Answer::from("co,de,ka").to_string(), "co,de,ka");
+        assert_eq!(Answer::None.to_string(), "-");
+        assert_eq!(Answer::from(None::<i32>), Answer::None);
+        assert_eq!(Answer::from(V2::new(6, 1)).kind(), "position");
+
+        const PARAMS: &[Param] = &[Param::of::<usize>("take", "12")];
+        let mut params = Params::new(PARAMS);
+        assert_eq!(params.set("take", "20"), Ok(()));
+        assert_eq!(params.get::<usize>("take"), 20);
+        assert_eq!(
+            params.set("take", "abc"),
+            Err(String::from("invalid value 'abc' for param 'take': expected a usize"))
+        );
+        assert!(params.set("tkae", "20").is_err());
+    }
+}
@@ -0,0 +1,263 @@
+use std::time::Duration;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::FetchConfig;
+use crate::utils::*;
+
+// every answer sent, with what the site said (not committed, it's a local record)
+pub const GUESSES_FILE: &str = "guesses.jsonl";
+
+//--------------------------------------------------------------------------------
+// response
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Wait,          // sent too soon after the previous one, not even checked
+    AlreadySolved, // or the part is locked
+    Unknown,
+}
+
+impl Verdict {
+    // the site told us this answer is not the one
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+// the page after a POST has one paragraph in an <article>. wrong answers come with a delay
+// before the next try ("please wait one minute"), early ones with what is left ("You have 4m 33s
+// left to wait")
+pub fn parse_response(html: &str) -> (Verdict, Option<Duration>) {
+    let verdict = if html.contains("That's the right answer") {
+        Verdict::Right
+    } else if html.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if html.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if html.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if html.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if html.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    };
+
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let delay = Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap();
+    // only digits are captured: a number too big to parse is not worth a panic, it waits 0
+    let num = |s: &str| s.parse::<u64>().unwrap_or(0);
+    let wait = if let Some(c) = left.captures(html) {
+        let minutes = c.get(1).map_or(0, |m| num(m.as_str()));
+        Some(Duration::from_secs(minutes * 60 + num(&c[2])))
+    } else if let Some(c) = delay.captures(html) {
+        let minutes = if &c[1] == "one" { 1 } else { num(&c[1]) };
+        Some(Duration::from_secs(minutes * 60))
+    } else {
+        None
+    };
+    (verdict, wait)
+}
+
+//--------------------------------------------------------------------------------
+// guesses
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Guess {
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub wait_until: Option<u64>, // no submission before that (unix time)
+}
+
+pub fn append(file: &str, guess: &Guess) -> Result<(), String> {
+    append_jsonl(file, std::slice::from_ref(guess))
+}
+
+pub fn load(file: &str) -> Result<Vec<Guess>, String> {
+    load_jsonl(file)
+}
+
+// why this answer shouldn't be sent, from what we already know: the cool-down of the site (it
+// applies to every puzzle), the part already solved, the same wrong answer, or one outside the
+// too high / too low bounds
+pub fn check_guess(guesses: &[Guess], year: u32, day: u32, part: u8, answer: &str, now: u64) -> Result<(), String> {
+    if let Some(until) = guesses
+        .iter()
+        .filter_map(|g| g.wait_until)
+        .max()
+        .filter(|&until| until > now)
+    {
+        return Err(format!(
+            "cool-down: wait {} before submitting again",
+            fmt_duration(Duration::from_secs(until - now))
+        ));
+    }
+    let same_part = guesses
+        .iter()
+        .filter(|g| (g.year, g.day, g.part) == (year, day, part))
+        .collect::<Vec<_>>();
+    if let Some(right) = same_part.iter().find(|g| g.verdict == Verdict::Right) {
+        return Err(format!("already solved, the answer was {}", right.answer));
+    }
+    if let Some(wrong) = same_part.iter().find(|g| g.answer == answer && g.verdict.is_wrong()) {
+        return Err(format!("{} was already sent, and was wrong", wrong.answer));
+    }
+    if let Ok(n) = answer.parse::<i128>() {
+        let bound = |verdict: Verdict| {
+            same_part
+                .iter()
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).filter(|&high| n >= high).min() {
+            return Err(format!("{} was too high, {} can't be right", high, n));
+        }
+        if let Some(low) = bound(Verdict::TooLow).filter(|&low| n <= low).max() {
+            return Err(format!("{} was too low, {} can't be right", low, n));
+        }
+    }
+    Ok(())
+}
+
+//--------------------------------------------------------------------------------
+// submit
+//--------------------------------------------------------------------------------
+
+// check the answer against what we know, send it, and record what the site said
+pub fn submit(config: &FetchConfig, guesses_file: &str, day: u32, part: u8, answer: &str) -> Result<Guess, String> {
+    let guesses = load(guesses_file)?;
+    let now = now_secs();
+    check_guess(&guesses, config.year, day, part, answer, now)?;
+
+    let html = config.post_form(
+        &config.url(day, "/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let (verdict, wait) = parse_response(&html);
+    let guess = Guess {
+        timestamp: now,
+        year: config.year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        wait_until: wait.map(|wait| now + wait.as_secs()),
+    };
+    append(guesses_file, &guess)?;
+    Ok(guess)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::*;
+
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. Please wait one minute \
+                           before trying again.</p></article>";
+    const EARLY: &str = "<article><p>You gave an answer too recently. You have 4m 33s left to wait.</p></article>";
+
+    // a mock of the site: answers each POST with the next page of `pages`, and records the forms
+    fn mock_server(pages: Vec<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let forms = Arc::new(Mutex::new(vec![]));
+        let recorded = forms.clone();
+        thread::spawn(move || {
+            for (stream, page) in listener.incoming().zip(pages) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.trim().strip_prefix("Content-Length: ") {
+                        length = value.parse().unwrap();
+                    }
+                }
+                let mut form = vec![0; length];
+                reader.read_exact(&mut form).unwrap();
+                recorded.lock().unwrap().push(String::from_utf8(form).unwrap());
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    page.len(),
+                    page
+                )
+                .unwrap();
+            }
+        });
+        (format!("http://{}", addr), forms)
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(parse_response(RIGHT), (Verdict::Right, None));
+        assert_eq!(
+            parse_response(TOO_LOW),
+            (Verdict::TooLow, Some(Duration::from_secs(60)))
+        );
+        assert_eq!(parse_response(EARLY), (Verdict::Wait, Some(Duration::from_secs(273))));
+
+        let (base_url, forms) = mock_server(vec![TOO_LOW, RIGHT]);
+        let config = FetchConfig {
+            base_url,
+            session: Some(String::from("abc")),
+            year: 2024,
+            data_dir: String::new(),
+        };
+        let file = std::env::temp_dir().join(format!("aoc24_guesses_{}.jsonl", std::process::id()));
+        let file = file.to_string_lossy().to_string();
+
+        let guess = submit(&config, &file, 3, 2, "100").unwrap();
+        assert_eq!(guess.verdict, Verdict::TooLow);
+        assert_eq!(forms.lock().unwrap()[0], "level=2&answer=100");
+        // still cooling down, nothing is sent
+        assert!(submit(&config, &file, 3, 2, "150")
+            .unwrap_err()
+            .starts_with("cool-down"));
+
+        // once the cool-down is over: a known wrong answer or one under the bound is refused, locally
+        let mut guesses = load(&file).unwrap();
+        let later = guesses[0].wait_until.unwrap() + 1;
+        assert!(check_guess(&guesses, 2024, 3, 2, "100", later).is_err());
+        assert!(check_guess(&guesses, 2024, 3, 2, "99", later).is_err());
+        assert!(check_guess(&guesses, 2024, 3, 2, "150", later).is_ok());
+        assert!(check_guess(&guesses, 2024, 3, 1, "100", later).is_ok());
+
+        guesses[0].wait_until = None;
+        fs::write(&file, serde_json::to_string(&guesses[0]).unwrap() + "\n").unwrap();
+        assert_eq!(submit(&config, &file, 3, 2, "150").unwrap().verdict, Verdict::Right);
+        assert_eq!(load(&file).unwrap().len(), 2);
+        assert!(submit(&config, &file, 3, 2, "151")
+            .unwrap_err()
+            .starts_with("already solved"));
+        assert_eq!(forms.lock().unwrap().len(), 2);
+
+        fs::remove_file(&file).unwrap();
+    }
+}
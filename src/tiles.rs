@@ -0,0 +1,155 @@
+use std::fs;
+
+use crate::answers::Answers;
+use crate::{data_dir, Year};
+
+pub const TILES_DIR: &str = ".aoc_tiles/tiles";
+pub const README_FILE: &str = "README.md";
+
+const BEGIN: &str = "<!-- AOC TILES BEGIN -->";
+const END: &str = "<!-- AOC TILES END -->";
+
+//--------------------------------------------------------------------------------
+// tiles
+//--------------------------------------------------------------------------------
+
+// a star is a part whose answer on the real input is in the manifest (so `verify` checks it),
+// no times: the tiles are committed and the history is per machine
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tile {
+    pub day: u32,
+    pub title: String,
+    pub stars: [bool; 2],
+}
+
+pub fn year_tiles(year: &Year, answers: &Answers) -> Vec<Tile> {
+    year.days
+        .iter()
+        .map(|d| {
+            let input = format!("{}/{:02}_input.txt", data_dir(year.year), d.day);
+            Tile {
+                day: d.day,
+                title: d.title.to_string(),
+                stars: [1, 2].map(|part| answers.expected(d.day, &input, part).is_some()),
+            }
+        })
+        .collect()
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// the day in big, its title under it, and a star for each part on the right
+pub fn tile_svg(tile: &Tile) -> String {
+    let title = match tile.title.chars().count() > 24 {
+        true => tile.title.chars().take(23).collect::<String>() + "…",
+        false => tile.title.clone(),
+    };
+    let mut svg = String::from(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200\" height=\"80\" viewBox=\"0 0 200 80\" \
+         font-family=\"monospace\">\n  <rect width=\"200\" height=\"80\" rx=\"8\" fill=\"#0f0f23\"/>\n",
+    );
+    svg += &format!(
+        "  <text x=\"12\" y=\"40\" font-size=\"32\" font-weight=\"bold\" fill=\"#ffffff\">{:02}</text>\n",
+        tile.day
+    );
+    svg += &format!(
+        "  <text x=\"12\" y=\"66\" font-size=\"11\" fill=\"#cccccc\">{}</text>\n",
+        escape_xml(&title)
+    );
+    for (i, star) in tile.stars.iter().enumerate() {
+        let y = 24 + 20 * i;
+        let color = if *star { "#ffff66" } else { "#333340" };
+        svg += &format!(
+            "  <text x=\"84\" y=\"{}\" font-size=\"13\" fill=\"#cccccc\">p{} <tspan fill=\"{}\">★</tspan></text>\n",
+            y,
+            i + 1,
+            color
+        );
+    }
+    svg + "</svg>\n"
+}
+
+pub fn nb_stars(tiles: &[Tile]) -> usize {
+    tiles.iter().flat_map(|t| t.stars).filter(|&star| star).count()
+}
+
+//--------------------------------------------------------------------------------
+// readme
+//--------------------------------------------------------------------------------
+
+// the latest year first, each tile links to its day's source
+pub fn readme_block(years: &[(u32, Vec<Tile>)]) -> String {
+    let mut block = String::new();
+    for (year, tiles) in years.iter().rev() {
+        block += &format!(
+            "<h1 align=\"center\">\n  {} - {} ⭐ - Rust\n</h1>\n",
+            year,
+            nb_stars(tiles)
+        );
+        for tile in tiles {
+            block += &format!(
+                "<a href=\"src/y{}/day{:02}.rs\">\n  <img src=\"{}/{}/{:02}.svg\" width=\"161px\">\n</a>\n",
+                year, tile.day, TILES_DIR, year, tile.day
+            );
+        }
+    }
+    block
+}
+
+// everything between the markers is replaced, the rest of the readme is untouched
+pub fn replace_block(readme: &str, block: &str) -> Result<String, String> {
+    let missing = || format!("no {} ... {} block in the readme", BEGIN, END);
+    let start = readme.find(BEGIN).ok_or_else(missing)? + BEGIN.len();
+    let end = start + readme[start..].find(END).ok_or_else(missing)?;
+    Ok(format!("{}\n{}{}", &readme[..start], block, &readme[end..]))
+}
+
+// one svg per day in TILES_DIR/YEAR/NN.svg, returns the files written
+pub fn write_tiles(tiles_dir: &str, year: u32, tiles: &[Tile]) -> Result<Vec<String>, String> {
+    let dir = format!("{}/{}", tiles_dir, year);
+    fs::create_dir_all(&dir).map_err(|err| format!("cannot create {}: {}", dir, err))?;
+    let mut written = vec![];
+    for tile in tiles {
+        let file = format!("{}/{:02}.svg", dir, tile.day);
+        fs::write(&file, tile_svg(tile)).map_err(|err| format!("cannot write {}: {}", file, err))?;
+        written.push(file);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let year = crate::find_year(2024).unwrap();
+        let answers = Answers::from_str("[day01]\n\"data/2024/01_input.txt\" = { p1 = \"11\" }\n").unwrap();
+        let tiles = year_tiles(year, &answers);
+        assert_eq!(tiles.len(), 25);
+        assert_eq!(tiles[0].stars, [true, false]);
+        assert_eq!(nb_stars(&tiles), 1);
+
+        let svg = tile_svg(&tiles[0]);
+        assert!(svg.contains(">01</text>"));
+        assert!(svg.contains("p1 <tspan fill=\"#ffff66\">★</tspan></text>"));
+        assert!(svg.contains("p2 <tspan fill=\"#333340\">★</tspan></text>"));
+
+        let block = readme_block(&[(2024, tiles[..1].to_vec())]);
+        let readme = format!("{}\nold\n{}\n# title\n", BEGIN, END);
+        assert_eq!(
+            replace_block(&readme, &block).unwrap(),
+            format!(
+                "{}\n<h1 align=\"center\">\n  2024 - 1 ⭐ - Rust\n</h1>\n<a href=\"src/y2024/day01.rs\">\n  \
+                 <img src=\".aoc_tiles/tiles/2024/01.svg\" width=\"161px\">\n</a>\n{}\n# title\n",
+                BEGIN, END
+            )
+        );
+        assert!(replace_block("# title\n", &block).is_err());
+    }
+}
@@ -1,39 +1,161 @@
 use std::{
-    fmt::{self, Display},
-    fs,
-    time::{Duration, Instant},
+    fmt::{self, Debug, Display},
+    fs::{self, OpenOptions},
+    hash::Hash,
+    io::Write,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Rem, Sub, SubAssign},
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::parse::*;
+
 //--------------------------------------------------------------------------------
 // number parsing
 //--------------------------------------------------------------------------------
 
-pub fn toi64(s: &str) -> i64 {
-    s.parse::<i64>().unwrap()
+// fallible, for parsing input files (the error knows where the token is)
+
+pub fn try_toi64(s: &str) -> Result<i64, ParseError> {
+    parse_num(s, "an integer")
 }
 
-pub fn tou64(s: &str) -> u64 {
-    s.parse::<u64>().unwrap()
+pub fn try_tou64(s: &str) -> Result<u64, ParseError> {
+    parse_num(s, "a positive integer")
 }
 
-pub fn toi32(s: &str) -> i32 {
-    s.parse::<i32>().unwrap()
+pub fn try_toi32(s: &str) -> Result<i32, ParseError> {
+    parse_num(s, "an integer")
 }
 
-pub fn toi128(s: &str) -> i128 {
-    s.parse::<i128>().unwrap()
+pub fn try_toi128(s: &str) -> Result<i128, ParseError> {
+    parse_num(s, "an integer")
 }
 
-pub fn tou32(s: &str) -> u32 {
-    s.parse::<u32>().unwrap()
+pub fn try_tou32(s: &str) -> Result<u32, ParseError> {
+    parse_num(s, "a positive integer")
 }
 
-pub fn tousize(s: &str) -> usize {
-    s.parse::<usize>().unwrap()
+pub fn try_tousize(s: &str) -> Result<usize, ParseError> {
+    parse_num(s, "a positive integer")
 }
 
-pub fn c_tou32(c: char) -> u32 {
-    c.to_digit(10).unwrap()
+pub fn try_c_tou32(c: char) -> Result<u32, ParseError> {
+    c.to_digit(10)
+        .ok_or_else(|| ParseError::unlocated(&c.to_string(), "a digit"))
+}
+
+//--------------------------------------------------------------------------------
+// coordinates
+//--------------------------------------------------------------------------------
+
+// the integer types a V2 / V3 can be made of: i32 for the grids, i64 or i128 when the puzzle
+// goes big
+pub trait Coord:
+    Copy
+    + Debug
+    + Display
+    + Hash
+    + Ord
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MINUS_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn rem_euclid(self, n: Self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const MINUS_ONE: $t = -1;
+
+            fn abs(self) -> $t {
+                self.abs()
+            }
+
+            fn signum(self) -> $t {
+                self.signum()
+            }
+
+            fn rem_euclid(self, n: $t) -> $t {
+                self.rem_euclid(n)
+            }
+        })*
+    };
+}
+
+impl_coord!(i32, i64, i128, isize);
+
+// the operators, the same on each axis
+macro_rules! impl_ops {
+    ($v:ident { $($axis:ident),* }) => {
+        impl<T: Coord> Add for $v<T> {
+            type Output = $v<T>;
+
+            fn add(self, other: $v<T>) -> $v<T> {
+                $v { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $v<T> {
+            fn add_assign(&mut self, other: $v<T>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> Sub for $v<T> {
+            type Output = $v<T>;
+
+            fn sub(self, other: $v<T>) -> $v<T> {
+                $v { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        impl<T: Coord> SubAssign for $v<T> {
+            fn sub_assign(&mut self, other: $v<T>) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $v<T> {
+            type Output = $v<T>;
+
+            fn mul(self, n: T) -> $v<T> {
+                $v { $($axis: self.$axis * n),* }
+            }
+        }
+
+        impl<T: Coord> Neg for $v<T> {
+            type Output = $v<T>;
+
+            fn neg(self) -> $v<T> {
+                $v { $($axis: -self.$axis),* }
+            }
+        }
+
+        // always positive (rem_euclid): `pos % size` wraps a position around a grid of that size
+        impl<T: Coord> Rem for $v<T> {
+            type Output = $v<T>;
+
+            fn rem(self, other: $v<T>) -> $v<T> {
+                $v { $($axis: self.$axis.rem_euclid(other.$axis)),* }
+            }
+        }
+    };
 }
 
 //--------------------------------------------------------------------------------
@@ -41,59 +163,152 @@ pub fn c_tou32(c: char) -> u32 {
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-pub struct V2 {
-    pub x: i32,
-    pub y: i32,
+pub struct V2<T = i32> {
+    pub x: T,
+    pub y: T,
 }
 
-impl V2 {
-    pub const UP: V2 = V2 { x: 0, y: -1 };
-    pub const DOWN: V2 = V2 { x: 0, y: 1 };
-    pub const LEFT: V2 = V2 { x: -1, y: 0 };
-    pub const RIGHT: V2 = V2 { x: 1, y: 0 };
+impl<T: Coord> V2<T> {
+    pub const ZERO: V2<T> = V2::new(T::ZERO, T::ZERO);
+    pub const UP: V2<T> = V2::new(T::ZERO, T::MINUS_ONE);
+    pub const DOWN: V2<T> = V2::new(T::ZERO, T::ONE);
+    pub const LEFT: V2<T> = V2::new(T::MINUS_ONE, T::ZERO);
+    pub const RIGHT: V2<T> = V2::new(T::ONE, T::ZERO);
+
+    // clockwise, starting from the top
+    pub const DIRS8: [V2<T>; 8] = [
+        V2::new(T::ZERO, T::MINUS_ONE),
+        V2::new(T::ONE, T::MINUS_ONE),
+        V2::new(T::ONE, T::ZERO),
+        V2::new(T::ONE, T::ONE),
+        V2::new(T::ZERO, T::ONE),
+        V2::new(T::MINUS_ONE, T::ONE),
+        V2::new(T::MINUS_ONE, T::ZERO),
+        V2::new(T::MINUS_ONE, T::MINUS_ONE),
+    ];
 
-    pub fn new(x: i32, y: i32) -> V2 {
+    // the steps of a HexDir, in its order
+    pub const HEX_DIRS: [V2<T>; 6] = [
+        V2::new(T::ZERO, T::MINUS_ONE),
+        V2::new(T::ONE, T::MINUS_ONE),
+        V2::new(T::ONE, T::ZERO),
+        V2::new(T::ZERO, T::ONE),
+        V2::new(T::MINUS_ONE, T::ONE),
+        V2::new(T::MINUS_ONE, T::ZERO),
+    ];
+
+    pub const fn new(x: T, y: T) -> V2<T> {
         V2 { x, y }
     }
 
-    pub fn from_vec(v: &[i32]) -> V2 {
+    pub fn from_vec(v: &[T]) -> V2<T> {
         V2::new(v[0], v[1])
     }
 
-    pub fn scale(&self, n: i32) -> V2 {
-        V2::new(self.x * n, self.y * n)
+    // one step in a Dir, a Dir8 or a HexDir
+    pub fn add_dir(&self, dir: &impl Direction) -> V2<T> {
+        *self + dir.step()
     }
 
-    pub fn add(&self, other: &V2) -> V2 {
-        V2::new(self.x + other.x, self.y + other.y)
+    // number of steps between the two, moving like a rook (one axis at a time)
+    pub fn manhattan(&self, other: &V2<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
     }
 
-    pub fn modulo(&self, other: &V2) -> V2 {
-        V2::new(self.x.rem_euclid(other.x), self.y.rem_euclid(other.y))
+    // number of steps between the two, moving like a king (diagonals allowed)
+    pub fn chebyshev(&self, other: &V2<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
     }
 
-    pub fn sub(&self, other: &V2) -> V2 {
-        V2::new(self.x - other.x, self.y - other.y)
+    // number of steps between the two on a hex grid (see HexDir)
+    pub fn hex_distance(&self, other: &V2<T>) -> T {
+        let (dx, dy) = (self.x - other.x, self.y - other.y);
+        dx.abs().max(dy.abs()).max((dx + dy).abs())
     }
 
-    pub fn add_dir(&self, dir: &Dir) -> V2 {
-        match dir {
-            Dir::Up => self.add(&V2::UP),
-            Dir::Down => self.add(&V2::DOWN),
-            Dir::Left => self.add(&V2::LEFT),
-            Dir::Right => self.add(&V2::RIGHT),
-        }
+    // the determinant of the two: 0 when they are colinear
+    pub fn cross(&self, other: &V2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    // y goes down, so clockwise is up -> right -> down -> left, as on the screen
+    pub fn rotate_cw(&self) -> V2<T> {
+        V2::new(-self.y, self.x)
+    }
+
+    pub fn rotate_ccw(&self) -> V2<T> {
+        V2::new(self.y, -self.x)
+    }
+
+    // -1, 0 or 1 on each axis: the step to take to get closer to self
+    pub fn signum(&self) -> V2<T> {
+        V2::new(self.x.signum(), self.y.signum())
     }
 
-    pub fn neighbors(&self) -> Vec<V2> {
+    pub fn neighbors(&self) -> Vec<V2<T>> {
         [V2::UP, V2::DOWN, V2::LEFT, V2::RIGHT]
             .iter()
-            .map(|dir| self.add(dir))
+            .map(|&dir| *self + dir)
             .collect()
     }
+
+    // with the diagonals, clockwise from the top
+    pub fn neighbors8(&self) -> Vec<V2<T>> {
+        V2::DIRS8.iter().map(|&dir| *self + dir).collect()
+    }
+}
+
+impl_ops!(V2 { x, y });
+
+// the methods the operators replaced, kept for the code still calling them
+impl<T: Coord> V2<T> {
+    #[deprecated(note = "use `*self * n`")]
+    pub fn scale(&self, n: T) -> V2<T> {
+        *self * n
+    }
+
+    #[deprecated(note = "use `*self + *other`")]
+    pub fn add(&self, other: &V2<T>) -> V2<T> {
+        *self + *other
+    }
+
+    #[deprecated(note = "use `*self % *other`")]
+    pub fn modulo(&self, other: &V2<T>) -> V2<T> {
+        *self % *other
+    }
+
+    #[deprecated(note = "use `*self - *other`")]
+    pub fn sub(&self, other: &V2<T>) -> V2<T> {
+        *self - *other
+    }
 }
 
-impl Display for V2 {
+impl<T> From<(T, T)> for V2<T> {
+    fn from((x, y): (T, T)) -> V2<T> {
+        V2 { x, y }
+    }
+}
+
+impl<T> From<V2<T>> for (T, T) {
+    fn from(v: V2<T>) -> (T, T) {
+        (v.x, v.y)
+    }
+}
+
+// "x,y", as in the inputs
+impl<T: Coord> FromStr for V2<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<V2<T>, ParseError> {
+        let (x, y) = split_once(s, ",")?;
+        Ok(V2::new(
+            parse_num(x.trim(), "an integer")?,
+            parse_num(y.trim(), "an integer")?,
+        ))
+    }
+}
+
+impl<T: Display> Display for V2<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({},{})", self.x, self.y)
     }
@@ -104,16 +319,124 @@ impl Display for V2 {
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-pub struct V3 {
-    pub x: i32,
-    pub y: i32,
-    pub z: i32,
+pub struct V3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> V3<T> {
+    pub const ZERO: V3<T> = V3::new(T::ZERO, T::ZERO, T::ZERO);
+
+    // one step along each axis, both ways
+    pub const DIRS6: [V3<T>; 6] = [
+        V3::new(T::ONE, T::ZERO, T::ZERO),
+        V3::new(T::MINUS_ONE, T::ZERO, T::ZERO),
+        V3::new(T::ZERO, T::ONE, T::ZERO),
+        V3::new(T::ZERO, T::MINUS_ONE, T::ZERO),
+        V3::new(T::ZERO, T::ZERO, T::ONE),
+        V3::new(T::ZERO, T::ZERO, T::MINUS_ONE),
+    ];
+
+    pub const fn new(x: T, y: T, z: T) -> V3<T> {
+        V3 { x, y, z }
+    }
+
+    pub fn from_vec(v: &[T]) -> V3<T> {
+        V3::new(v[0], v[1], v[2])
+    }
+
+    pub fn manhattan(&self, other: &V3<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &V3<T>) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn cross(&self, other: &V3<T>) -> V3<T> {
+        V3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn dot(&self, other: &V3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn signum(&self) -> V3<T> {
+        V3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    // the 6 cubes sharing a face with this one
+    pub fn neighbors6(&self) -> Vec<V3<T>> {
+        V3::DIRS6.iter().map(|&dir| *self + dir).collect()
+    }
+
+    // the 26 cubes around this one (sharing a face, an edge or a corner)
+    pub fn neighbors26(&self) -> Vec<V3<T>> {
+        let steps = [T::MINUS_ONE, T::ZERO, T::ONE];
+        let mut res = vec![];
+        for dx in steps {
+            for dy in steps {
+                for dz in steps {
+                    if (dx, dy, dz) != (T::ZERO, T::ZERO, T::ZERO) {
+                        res.push(*self + V3::new(dx, dy, dz));
+                    }
+                }
+            }
+        }
+        res
+    }
+}
+
+impl_ops!(V3 { x, y, z });
+
+impl<T> From<(T, T, T)> for V3<T> {
+    fn from((x, y, z): (T, T, T)) -> V3<T> {
+        V3 { x, y, z }
+    }
+}
+
+impl<T> From<V3<T>> for (T, T, T) {
+    fn from(v: V3<T>) -> (T, T, T) {
+        (v.x, v.y, v.z)
+    }
+}
+
+// "x,y,z"
+impl<T: Coord> FromStr for V3<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<V3<T>, ParseError> {
+        let (x, yz) = split_once(s, ",")?;
+        let (y, z) = split_once(yz, ",")?;
+        let num = |s: &str| parse_num(s.trim(), "an integer");
+        Ok(V3::new(num(x)?, num(y)?, num(z)?))
+    }
+}
+
+impl<T: Display> Display for V3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
 }
 
 //--------------------------------------------------------------------------------
 // direction
 //--------------------------------------------------------------------------------
 
+// anything moving a position by one step: the 4 directions of a grid, the 8 with the diagonals,
+// or the 6 of a hex grid
+pub trait Direction: Copy {
+    fn step<T: Coord>(&self) -> V2<T>;
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub enum Dir {
     Up,
@@ -123,6 +446,12 @@ pub enum Dir {
 }
 
 impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn all() -> impl Iterator<Item = Dir> {
+        Dir::ALL.into_iter()
+    }
+
     pub fn rot_right(&self) -> Dir {
         match self {
             Dir::Up => Dir::Right,
@@ -141,6 +470,10 @@ impl Dir {
         }
     }
 
+    pub fn opposite(&self) -> Dir {
+        self.rot_right().rot_right()
+    }
+
     #[allow(clippy::wrong_self_convention)]
     pub fn to_v2(&self) -> &V2 {
         match self {
@@ -152,107 +485,364 @@ impl Dir {
     }
 }
 
+impl Direction for Dir {
+    fn step<T: Coord>(&self) -> V2<T> {
+        match self {
+            Dir::Up => V2::UP,
+            Dir::Down => V2::DOWN,
+            Dir::Left => V2::LEFT,
+            Dir::Right => V2::RIGHT,
+        }
+    }
+}
+
+// with the diagonals, clockwise from the top (the order of V2::DIRS8)
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Dir8> {
+        Dir8::ALL.into_iter()
+    }
+
+    pub fn diagonals() -> impl Iterator<Item = Dir8> {
+        Dir8::all().filter(|dir| dir.is_diagonal())
+    }
+
+    // by 45° steps, clockwise
+    fn turn(&self, nb_steps: usize) -> Dir8 {
+        Dir8::ALL[(*self as usize + nb_steps) % 8]
+    }
+
+    pub fn rot_right45(&self) -> Dir8 {
+        self.turn(1)
+    }
+
+    pub fn rot_left45(&self) -> Dir8 {
+        self.turn(7)
+    }
+
+    pub fn rot_right(&self) -> Dir8 {
+        self.turn(2)
+    }
+
+    pub fn rot_left(&self) -> Dir8 {
+        self.turn(6)
+    }
+
+    pub fn opposite(&self) -> Dir8 {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+}
+
+impl Direction for Dir8 {
+    fn step<T: Coord>(&self) -> V2<T> {
+        V2::DIRS8[*self as usize]
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Dir8 {
+        match dir {
+            Dir::Up => Dir8::Up,
+            Dir::Down => Dir8::Down,
+            Dir::Left => Dir8::Left,
+            Dir::Right => Dir8::Right,
+        }
+    }
+}
+
+// a hex grid with flat-topped hexes, in axial coordinates: x is the column, and y goes down the
+// column (so ne/sw also move along y). clockwise from the top
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum HexDir {
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+impl HexDir {
+    pub const ALL: [HexDir; 6] = [HexDir::N, HexDir::NE, HexDir::SE, HexDir::S, HexDir::SW, HexDir::NW];
+
+    pub fn all() -> impl Iterator<Item = HexDir> {
+        HexDir::ALL.into_iter()
+    }
+
+    // "n", "ne", "se"...
+    pub fn from_str(s: &str) -> Result<HexDir, ParseError> {
+        match s {
+            "n" => Ok(HexDir::N),
+            "ne" => Ok(HexDir::NE),
+            "se" => Ok(HexDir::SE),
+            "s" => Ok(HexDir::S),
+            "sw" => Ok(HexDir::SW),
+            "nw" => Ok(HexDir::NW),
+            _ => Err(ParseError::new(s, "a hex direction")),
+        }
+    }
+
+    // by 60° steps, clockwise
+    fn turn(&self, nb_steps: usize) -> HexDir {
+        HexDir::ALL[(*self as usize + nb_steps) % 6]
+    }
+
+    pub fn rot_right(&self) -> HexDir {
+        self.turn(1)
+    }
+
+    pub fn rot_left(&self) -> HexDir {
+        self.turn(5)
+    }
+
+    pub fn opposite(&self) -> HexDir {
+        self.turn(3)
+    }
+}
+
+impl Direction for HexDir {
+    fn step<T: Coord>(&self) -> V2<T> {
+        V2::HEX_DIRS[*self as usize]
+    }
+}
+
 //--------------------------------------------------------------------------------
 // matrix
 //--------------------------------------------------------------------------------
 
+// the cells are stored row after row in one vec: (x, y) is at y * width + x
 #[derive(Debug, Clone, PartialEq)]
 pub struct Matrix<T> {
-    pub matrix: Vec<Vec<T>>,
+    cells: Vec<T>,
     pub width: i32,
     pub height: i32,
 }
 
 // base matrix
 impl<T: Clone + PartialEq> Matrix<T> {
+    // all the rows must have the same length
     pub fn from_vec(matrix: Vec<Vec<T>>) -> Matrix<T> {
         let height = matrix.len() as i32;
-        let width = matrix[0].len() as i32;
-        Matrix { matrix, width, height }
+        let width = matrix.first().map_or(0, |row| row.len()) as i32;
+        assert!(
+            matrix.iter().all(|row| row.len() == width as usize),
+            "rows of different lengths"
+        );
+        let cells = matrix.into_iter().flatten().collect();
+        Matrix { cells, width, height }
     }
 
-    pub fn clone_without_border(&self) -> Matrix<T> {
-        let mut new_matrix = vec![];
-        for y in 1..self.height - 1 {
-            let mut row = vec![];
-            for x in 1..self.width - 1 {
-                row.push(self.matrix[y as usize][x as usize].clone());
-            }
-            new_matrix.push(row);
+    pub fn with_size(width: usize, height: usize, default: T) -> Matrix<T> {
+        Matrix {
+            cells: vec![default; width * height],
+            width: width as i32,
+            height: height as i32,
         }
-        Matrix::from_vec(new_matrix)
     }
 
-    pub fn find_first(&self, value: T) -> Option<V2> {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                if self.matrix[y as usize][x as usize] == value {
-                    return Some(V2::new(x, y));
+    pub fn from_str(content: &str, convert: fn(char) -> T) -> Matrix<T> {
+        let matrix: Vec<Vec<T>> = content
+            .lines()
+            .map(|line| line.chars().map(convert).collect())
+            .collect();
+        Matrix::from_vec(matrix)
+    }
+
+    // an error on a row of a different length instead of a panic
+    pub fn try_from_str(content: &str, convert: fn(char) -> T) -> Result<Matrix<T>, ParseError> {
+        Matrix::try_from_str_with(content, |c| Some(convert(c)), "")
+    }
+
+    // also an error on a char convert rejects, expecting what `expected` describes
+    pub fn try_from_str_with(
+        content: &str,
+        convert: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Matrix<T>, ParseError> {
+        let mut matrix: Vec<Vec<T>> = vec![];
+        for line in content.lines() {
+            let mut row = vec![];
+            for (i, c) in line.char_indices() {
+                row.push(convert(c).ok_or_else(|| ParseError::new(&line[i..i + c.len_utf8()], expected))?);
+            }
+            if let Some(first) = matrix.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(line, &format!("a row of {} cells", first.len())));
                 }
             }
+            matrix.push(row);
         }
-        None
+        Ok(Matrix::from_vec(matrix))
     }
 
-    pub fn find_all(&self, value: T) -> Vec<V2> {
-        let mut res = vec![];
-        for y in 0..self.height {
-            for x in 0..self.width {
-                if self.matrix[y as usize][x as usize] == value {
-                    res.push(V2::new(x, y));
-                }
-            }
+    // empty when there is nothing inside the border
+    pub fn clone_without_border(&self) -> Matrix<T> {
+        if self.width < 3 || self.height < 3 {
+            return Matrix::from_vec(vec![]);
         }
-        res
+        let rows = (1..self.height - 1).map(|y| self.row(y as usize)[1..self.width as usize - 1].to_vec());
+        Matrix::from_vec(rows.collect())
+    }
+
+    pub fn find_first(&self, value: T) -> Option<V2> {
+        self.cells.iter().position(|v| *v == value).map(|i| self.pos_of(i))
+    }
+
+    // a cell the puzzle can't do without (the start, the robot...), or an error at the end of the
+    // input the matrix was read from
+    pub fn find_marker(&self, value: T, input: &str, expected: &str) -> Result<V2, ParseError> {
+        self.find_first(value)
+            .ok_or_else(|| ParseError::new(&input[input.len()..], expected))
+    }
+
+    pub fn find_all(&self, value: T) -> Vec<V2> {
+        self.iter().filter(|(_, v)| **v == value).map(|(pos, _)| pos).collect()
     }
 
     pub fn is_in(&self, pos: &V2) -> bool {
         pos.x >= 0 && pos.y >= 0 && pos.x < self.width && pos.y < self.height
     }
 
-    // return char at x, y or '.' if out of bounds
+    fn index_of(&self, pos: &V2) -> usize {
+        pos.y as usize * self.width as usize + pos.x as usize
+    }
+
+    fn pos_of(&self, i: usize) -> V2 {
+        V2::new((i % self.width as usize) as i32, (i / self.width as usize) as i32)
+    }
+
+    // a copy of the value at pos, None if out of bounds
     pub fn get(&self, pos: &V2) -> Option<T> {
-        if self.is_in(pos) {
-            Some(self.matrix[pos.y as usize][pos.x as usize].clone())
-        } else {
-            None
+        self.get_ref(pos).cloned()
+    }
+
+    // same without the copy, for the hot loops
+    pub fn get_ref(&self, pos: &V2) -> Option<&T> {
+        match self.is_in(pos) {
+            true => Some(&self.cells[self.index_of(pos)]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: &V2) -> Option<&mut T> {
+        match self.is_in(pos) {
+            true => {
+                let i = self.index_of(pos);
+                Some(&mut self.cells[i])
+            }
+            false => None,
         }
     }
 
+    /// # Safety
+    /// `pos` must be in the matrix (`is_in`), nothing is checked in release builds
+    pub unsafe fn get_unchecked(&self, pos: &V2) -> &T {
+        debug_assert!(self.is_in(pos), "{} out of the matrix", pos);
+        self.cells.get_unchecked(self.index_of(pos))
+    }
+
+    /// # Safety
+    /// `pos` must be in the matrix (`is_in`), nothing is checked in release builds
+    pub unsafe fn get_unchecked_mut(&mut self, pos: &V2) -> &mut T {
+        debug_assert!(self.is_in(pos), "{} out of the matrix", pos);
+        let i = self.index_of(pos);
+        self.cells.get_unchecked_mut(i)
+    }
+
+    // out of bounds is a no-op
     pub fn set(&mut self, pos: &V2, value: T) -> &mut Self {
-        if self.is_in(pos) {
-            self.matrix[pos.y as usize][pos.x as usize] = value;
+        if let Some(cell) = self.get_mut(pos) {
+            *cell = value;
         }
         self
     }
 
-    pub fn with_size(width: usize, height: usize, default: T) -> Matrix<T> {
-        let matrix = vec![vec![default; width]; height];
-        Matrix::from_vec(matrix)
+    pub fn row(&self, y: usize) -> &[T] {
+        let width = self.width as usize;
+        &self.cells[y * width..(y + 1) * width]
     }
 
-    pub fn from_str(content: &str, convert: fn(char) -> T) -> Matrix<T> {
-        let matrix: Vec<Vec<T>> = content
-            .lines()
-            .map(|line| line.chars().map(convert).collect())
-            .collect();
-        Matrix::from_vec(matrix)
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        let width = self.width as usize;
+        &mut self.cells[y * width..(y + 1) * width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    // every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (V2, &T)> {
+        self.cells.iter().enumerate().map(|(i, v)| (self.pos_of(i), v))
     }
 
     pub fn neighbors(&self, pos: &V2) -> Vec<V2> {
-        pos.neighbors().iter().filter(|nx| self.is_in(nx)).cloned().collect()
+        self.neighbors_in(pos, &Dir::ALL)
+    }
+
+    // with the diagonals
+    pub fn neighbors8(&self, pos: &V2) -> Vec<V2> {
+        self.neighbors_in(pos, &Dir8::ALL)
+    }
+
+    // one step away in each of `dirs`, and still in the matrix
+    pub fn neighbors_in(&self, pos: &V2, dirs: &[impl Direction]) -> Vec<V2> {
+        dirs.iter()
+            .map(|dir| pos.add_dir(dir))
+            .filter(|nx| self.is_in(nx))
+            .collect()
+    }
+}
+
+// panics out of bounds, like a vec
+impl<T: Clone + PartialEq> Index<V2> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, pos: V2) -> &T {
+        self.get_ref(&pos)
+            .unwrap_or_else(|| panic!("{} out of the {}x{} matrix", pos, self.width, self.height))
+    }
+}
+
+impl<T: Clone + PartialEq> IndexMut<V2> for Matrix<T> {
+    fn index_mut(&mut self, pos: V2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(&pos)
+            .unwrap_or_else(|| panic!("{} out of the {}x{} matrix", pos, width, height))
     }
 }
 
 impl<T: Display + Clone + PartialEq> Display for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                if let Some(value) = self.get(&V2::new(x, y)) {
-                    write!(f, "{}", value)?;
-                } else {
-                    write!(f, "*")?;
-                }
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
             }
             writeln!(f)?;
         }
@@ -276,6 +866,50 @@ pub fn fmt_duration(duration: Duration) -> String {
     }
 }
 
+pub fn fmt_bytes(bytes: u64) -> String {
+    if bytes >= 1 << 30 {
+        format!("{:.1}GB", bytes as f64 / (1u64 << 30) as f64)
+    } else if bytes >= 1 << 20 {
+        format!("{:.1}MB", bytes as f64 / (1u64 << 20) as f64)
+    } else if bytes >= 1 << 10 {
+        format!("{:.1}KB", bytes as f64 / (1u64 << 10) as f64)
+    } else {
+        format!("{}B", bytes)
+    }
+}
+
+//--------------------------------------------------------------------------------
+// jsonl
+//--------------------------------------------------------------------------------
+
+// one json value per line, for the local records (run history, guesses)
+pub fn append_jsonl<T: Serialize>(file: &str, values: &[T]) -> Result<(), String> {
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file)
+        .map_err(|err| format!("cannot open {}: {}", file, err))?;
+    for value in values {
+        let line = serde_json::to_string(value).map_err(|err| err.to_string())?;
+        writeln!(f, "{}", line).map_err(|err| format!("cannot write {}: {}", file, err))?;
+    }
+    Ok(())
+}
+
+// no file is no value yet. the errors say which line is wrong
+pub fn load_jsonl<T: DeserializeOwned>(file: &str) -> Result<Vec<T>, String> {
+    if !Path::new(file).exists() {
+        return Ok(vec![]);
+    }
+    let content = fs::read_to_string(file).map_err(|err| format!("cannot read {}: {}", file, err))?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| serde_json::from_str(line).map_err(|err| format!("{}:{}: {}", file, i + 1, err)))
+        .collect()
+}
+
 //--------------------------------------------------------------------------------
 // misc
 //--------------------------------------------------------------------------------
@@ -285,20 +919,160 @@ pub fn bool_to_u32(b: bool) -> u32 {
     b as u32
 }
 
+// short hash of HEAD, if we are in a git checkout
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 pub fn pp_day(txt: &str) {
     println!("\n#### {}", txt);
 }
 
-// time p1/p2 function with the content of the file
-pub fn time_it<R: fmt::Display>(p: fn(&str) -> R, p_str: &str, file: &str) {
+// time p1/p2 (or parsing)
+pub fn time_it<R>(p: impl FnOnce() -> R) -> (R, Duration) {
     let start = Instant::now();
-    let res = run_it(p, file);
-    let duration = start.elapsed();
-    println!("[{}] {} : {} -> {}", fmt_duration(duration), p_str, file, res);
+    let res = p();
+    (res, start.elapsed())
+}
+
+pub fn pp_result(p_str: &str, file: &str, duration: Duration, res: &str, status: &impl Display) {
+    println!(
+        "[{}] {} : {} -> {} {}",
+        fmt_duration(duration),
+        p_str,
+        file,
+        res,
+        status
+    );
 }
 
-// run p1/p2 function with the content of the file
-pub fn run_it<R>(p: fn(&str) -> R, file: &str) -> R {
-    let input = fs::read_to_string(file).expect("cannot read sample file");
-    p(input.trim_end())
+#[cfg(test)]
+mod tests {
+    use std::convert::identity;
+
+    use super::*;
+
+    #[test]
+    fn test() {
+        let (a, b) = (V2::new(1, 2), V2::new(4, -2));
+        assert_eq!(a + b, V2::new(5, 0));
+        assert_eq!(a - b, V2::new(-3, 4));
+        assert_eq!(a * 3, V2::new(3, 6));
+        assert_eq!(-a, V2::new(-1, -2));
+        assert_eq!(V2::new(-1, 7) % V2::new(5, 5), V2::new(4, 2));
+        #[allow(deprecated)]
+        // (with std::ops::Add in scope, `a.add(&b)` would pick the trait, hence the paths)
+        let old = (
+            V2::add(&a, &b),
+            V2::sub(&a, &b),
+            a.scale(3),
+            V2::new(-1, 7).modulo(&V2::new(5, 5)),
+        );
+        assert_eq!(old, (a + b, a - b, a * 3, V2::new(4, 2)));
+        assert_eq!((a.manhattan(&b), a.chebyshev(&b)), (7, 4));
+        let up: V2 = V2::UP;
+        assert_eq!(up.rotate_cw(), V2::RIGHT);
+        assert_eq!(up.rotate_ccw(), V2::LEFT);
+        assert_eq!(V2::new(-5, 0).signum(), V2::LEFT);
+        assert_eq!(V2::<i32>::ZERO.neighbors8()[..3], [V2::UP, V2::new(1, -1), V2::RIGHT]);
+        assert_eq!("3, -4".parse::<V2>(), Ok(V2::new(3, -4)));
+        assert!("3;4".parse::<V2>().is_err());
+        assert_eq!(V2::from((1, 2)), a);
+        assert_eq!(<(i32, i32)>::from(a), (1, 2));
+        let big = V2::<i64>::new(10_000_000_000, 3);
+        assert_eq!(big * 2 - V2::RIGHT, V2::new(19_999_999_999, 6));
+        assert_eq!(big.cross(&V2::new(1, 1)), 9_999_999_997);
+        assert_eq!("-10000000000,0".parse::<V2<i64>>(), Ok(V2::new(-10_000_000_000, 0)));
+
+        let c = V3::new(1, 2, 3);
+        assert_eq!(c + V3::new(1, 1, 1) * 2, V3::new(3, 4, 5));
+        assert_eq!((c.manhattan(&V3::ZERO), c.chebyshev(&V3::ZERO)), (6, 3));
+        assert_eq!(V3::new(1, 0, 0).cross(&V3::new(0, 1, 0)), V3::new(0, 0, 1));
+        assert_eq!(c.dot(&c), 14);
+        assert_eq!(c.neighbors6().len(), 6);
+        assert!(c.neighbors6().iter().all(|n| n.manhattan(&c) == 1));
+        assert_eq!(c.neighbors26().len(), 26);
+        assert!(c.neighbors26().iter().all(|n| n.chebyshev(&c) == 1));
+        assert_eq!("1, 2,3".parse::<V3>(), Ok(c));
+        assert!("1,2".parse::<V3>().is_err());
+        assert_eq!(c.to_string(), "(1,2,3)");
+
+        assert_eq!(Dir8::Up.rot_right45(), Dir8::UpRight);
+        assert_eq!(Dir8::Up.rot_left45(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.rot_right(), Dir8::UpRight);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+        assert_eq!(Dir8::all().count(), 8);
+        assert_eq!(Dir8::diagonals().collect::<Vec<_>>()[0], Dir8::UpRight);
+        assert_eq!(V2::new(2, 2).add_dir(&Dir8::DownLeft), V2::new(1, 3));
+        assert_eq!(V2::new(2, 2).add_dir(&Dir::Up), V2::new(2, 1));
+        assert!(Dir::all().all(|dir| Dir8::from(dir).step::<i32>() == *dir.to_v2()));
+        assert_eq!(Dir::Left.opposite(), Dir::Right);
+
+        let path = ["ne", "ne", "s", "s"].map(|s| HexDir::from_str(s).unwrap());
+        let end = path.iter().fold(V2::<i32>::ZERO, |pos, dir| pos.add_dir(dir));
+        assert_eq!(end.hex_distance(&V2::ZERO), 2);
+        assert!(HexDir::all().all(|dir| dir.step::<i32>().hex_distance(&V2::ZERO) == 1));
+        assert_eq!(HexDir::N.rot_right().rot_right(), HexDir::SE);
+        assert_eq!(HexDir::SW.opposite(), HexDir::NE);
+        assert!(HexDir::from_str("up").is_err());
+
+        let mut matrix = Matrix::from_str("#.^\n..#", identity);
+        assert_eq!((matrix.width, matrix.height), (3, 2));
+        assert_eq!(matrix.find_first('^'), Some(V2::new(2, 0)));
+        assert_eq!(matrix.find_all('#'), vec![V2::new(0, 0), V2::new(2, 1)]);
+        assert_eq!(matrix.get(&V2::new(1, 1)), Some('.'));
+        assert_eq!(matrix.get_ref(&V2::new(3, 0)), None);
+        assert_eq!(matrix.get_ref(&V2::new(0, -1)), None);
+        assert_eq!(matrix[V2::new(2, 1)], '#');
+        assert_eq!(unsafe { *matrix.get_unchecked(&V2::new(0, 0)) }, '#');
+        assert_eq!(matrix.row(1), &['.', '.', '#']);
+        assert_eq!(matrix.neighbors(&V2::new(0, 0)), vec![V2::new(0, 1), V2::new(1, 0)]);
+        assert_eq!(
+            matrix.neighbors8(&V2::new(0, 0)),
+            vec![V2::new(1, 0), V2::new(1, 1), V2::new(0, 1)]
+        );
+        assert_eq!(
+            matrix.neighbors_in(&V2::new(1, 1), &[HexDir::NE, HexDir::S]),
+            vec![V2::new(2, 0)]
+        );
+
+        matrix[V2::new(1, 1)] = 'X';
+        *matrix.get_mut(&V2::new(0, 1)).unwrap() = 'O';
+        matrix.set(&V2::new(5, 5), 'Z');
+        assert_eq!(matrix.to_string(), "#.^\nOX#\n");
+        assert_eq!(matrix.iter().nth(4), Some((V2::new(1, 1), &'X')));
+
+        let bordered = Matrix::from_str("####\n#ab#\n####", identity);
+        assert_eq!(bordered.clone_without_border(), Matrix::from_vec(vec![vec!['a', 'b']]));
+        assert_eq!(Matrix::from_str("##\n##", identity).clone_without_border().width, 0);
+        assert!(Matrix::try_from_str("ab\nc", identity).is_err());
+        assert_eq!(
+            Matrix::try_from_str("ab\ncd", identity),
+            Ok(Matrix::from_str("ab\ncd", identity))
+        );
+        assert!(Matrix::try_from_str_with("1.\n2x", |c| c.to_digit(10), "a digit").is_err());
+        assert_eq!(Matrix::with_size(2, 3, 0).rows().count(), 3);
+    }
 }
@@ -0,0 +1,107 @@
+use std::{
+    fs,
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+//--------------------------------------------------------------------------------
+// files
+//--------------------------------------------------------------------------------
+
+// the inputs of a day: data/YEAR/NN_*.txt (samples and real input)
+pub fn data_files(data_dir: &str, day: u32) -> Vec<String> {
+    let prefix = format!("{:02}_", day);
+    let mut files: Vec<String> = fs::read_dir(data_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+        .map(|name| format!("{}/{}", data_dir, name))
+        .collect();
+    files.sort();
+    files
+}
+
+// what triggers a rerun: the day's source, its inputs and the expected answers
+pub fn watched_files(src_dir: &str, data_dir: &str, answers_file: &str, day: u32) -> Vec<String> {
+    let mut files = vec![format!("{}/day{:02}.rs", src_dir, day), answers_file.to_string()];
+    files.extend(data_files(data_dir, day));
+    files
+}
+
+// files are listed again each time, so a new sample is picked up too
+pub fn snapshot(files: &[String]) -> Vec<(String, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|file| (file.clone(), fs::metadata(file).and_then(|m| m.modified()).ok()))
+        .collect()
+}
+
+//--------------------------------------------------------------------------------
+// watch
+//--------------------------------------------------------------------------------
+
+// this binary can't reload its own code: each change goes through `cargo run`, which rebuilds if
+// needed, then runs the day on all its files (failures come with a diff)
+fn rerun(year: u32, day: u32, release: bool) {
+    let (year, day) = (year.to_string(), day.to_string());
+    let mut args = vec!["run", "--quiet"];
+    if release {
+        args.push("--release");
+    }
+    args.extend(["--", "--year", &year, "run", &day, "--all-inputs"]);
+    match Command::new("cargo").args(&args).status() {
+        Ok(status) if !status.success() => println!("\n(exit status: {})", status),
+        Ok(_) => {}
+        Err(err) => println!("cannot run cargo: {}", err),
+    }
+}
+
+pub fn watch(year: u32, day: u32, release: bool, interval: Duration) -> ! {
+    let src_dir = format!("src/y{}", year);
+    let data_dir = crate::data_dir(year);
+    let answers_file = crate::answers::answers_file(year);
+    let files = || watched_files(&src_dir, &data_dir, &answers_file, day);
+    let mut last = None;
+    loop {
+        let current = snapshot(&files());
+        if last.as_ref() != Some(&current) {
+            // let the editor finish writing
+            thread::sleep(interval);
+            last = Some(snapshot(&files()));
+            print!("\x1b[2J\x1b[H"); // clear the screen
+            println!("watching {} files of day{:02} (ctrl-c to stop)", current.len(), day);
+            rerun(year, day, release);
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use crate::y2024::day03::Day03;
+
+    #[test]
+    fn test() {
+        let files = watched_files("src/y2024", "data/2024", "answers/2024.toml", 3);
+        // the source and the manifest, then every data file of the day, whatever new samples come
+        assert_eq!(files[..2], ["src/y2024/day03.rs", "answers/2024.toml"]);
+        assert!(files[2..].iter().all(|file| file.starts_with("data/2024/03_")));
+        assert!(Day03::RUNS.iter().all(|run| files.iter().any(|file| file == run.file)));
+        assert!(snapshot(&files).iter().all(|(_, modified)| modified.is_some()));
+
+        // a change is seen
+        let dir = std::env::temp_dir().join(format!("aoc24_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let dir = dir.to_string_lossy().to_string();
+        let before = snapshot(&data_files(&dir, 3));
+        fs::write(format!("{}/03_sample.txt", dir), "1").unwrap();
+        let after = snapshot(&data_files(&dir, 3));
+        assert_ne!(before, after);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
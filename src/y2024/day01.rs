@@ -0,0 +1,87 @@
+use crate::parse::*;
+use crate::solution::*;
+use crate::utils::*;
+
+type Lists = (Vec<i32>, Vec<i32>);
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+fn parse_line(line: &str) -> Result<(i32, i32), ParseError> {
+    let mut vals = line.split_ascii_whitespace();
+    let l = try_toi32(next_line(&mut vals, line, "a left id")?)?;
+    let r = try_toi32(next_line(&mut vals, line, "a right id")?)?;
+    Ok((l, r))
+}
+
+fn parse_input(input: &str) -> Result<Lists, ParseError> {
+    input.lines().map(parse_line).collect()
+}
+
+fn p1((left, right): &Lists) -> i32 {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    left.sort();
+    right.sort();
+
+    let pairs: Vec<(i32, i32)> = left.into_iter().zip(right).collect();
+    let sum = pairs.iter().map(|(l, r)| (r - l).abs()).sum::<i32>();
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+fn p2((left, right): &Lists) -> i32 {
+    let mut sum = 0;
+    for l in left.iter() {
+        // not super optimized, but it works
+        let nb_matches: i32 = right.iter().filter(|r| *r == l).count() as i32;
+        sum += nb_matches * l;
+    }
+
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "sort lists";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/01_sample.txt"),
+        Run::new(2, "data/2024/01_input.txt"),
+    ];
+
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn p1(lists: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(lists)
+    }
+
+    fn p2(lists: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(lists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day01>(1, "data/2024/01_sample.txt"), "11");
+        assert_eq!(solve_file::<Day01>(2, "data/2024/01_sample.txt"), "31");
+    }
+}
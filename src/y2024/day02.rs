@@ -1,15 +1,19 @@
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
 // p1
 //--------------------------------------------------------------------------------
 
-fn parse_line(line: &str) -> Vec<i32> {
-    line.split_ascii_whitespace().map(toi32).collect::<Vec<_>>()
+fn parse_line(line: &str) -> Result<Vec<i32>, ParseError> {
+    line.split_ascii_whitespace().map(try_toi32).collect()
 }
 
 fn is_safe_p1(line: &[i32]) -> bool {
-    let &[v1, v2] = line.array_chunks::<2>().next().unwrap();
+    // a report of a single level can't go the wrong way
+    let Some(&[v1, v2]) = line.first_chunk::<2>() else {
+        return true;
+    };
     let is_going_up = (v2 - v1) > 0;
 
     for window in line.windows(2) {
@@ -26,8 +30,8 @@ fn is_safe_p1(line: &[i32]) -> bool {
     true
 }
 
-fn p1(input: &str) -> usize {
-    input.lines().map(parse_line).filter(|l| is_safe_p1(l)).count()
+fn p1(lines: &[Vec<i32>]) -> usize {
+    lines.iter().filter(|l| is_safe_p1(l)).count()
 }
 
 //--------------------------------------------------------------------------------
@@ -47,18 +51,38 @@ fn is_safe_p2(line: &[i32]) -> bool {
         .any(|modified_line| is_safe_p1(&modified_line))
 }
 
-fn p2(input: &str) -> usize {
-    input.lines().map(parse_line).filter(|l| is_safe_p2(l)).count()
+fn p2(lines: &[Vec<i32>]) -> usize {
+    lines.iter().filter(|l| is_safe_p2(l)).count()
 }
 
 //--------------------------------------------------------------------------------
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day02: safe line");
-    time_it(p1, "p1", "data/02_input.txt");
-    time_it(p2, "p2", "data/02_input.txt");
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "safe line";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/02_input.txt"),
+        Run::new(2, "data/2024/02_input.txt"),
+    ];
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(parse_line).collect()
+    }
+
+    fn p1(lines: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(lines)
+    }
+
+    fn p2(lines: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(lines)
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +91,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/02_sample.txt"), 2);
-        assert_eq!(run_it(p2, "data/02_sample.txt"), 4);
+        assert_eq!(solve_file::<Day02>(1, "data/2024/02_sample.txt"), "2");
+        assert_eq!(solve_file::<Day02>(2, "data/2024/02_sample.txt"), "4");
     }
 }
@@ -1,22 +1,20 @@
-use regex::Regex;
+use regex::{Captures, Regex};
 
-use crate::utils::*;
+use crate::solution::*;
 
 //--------------------------------------------------------------------------------
 // p1
 //--------------------------------------------------------------------------------
 
+// the regex only captures 1 to 3 digits, so the numbers always parse
+fn mul(caps: &Captures) -> u32 {
+    caps[1].parse::<u32>().unwrap() * caps[2].parse::<u32>().unwrap()
+}
+
 fn p1(input: &str) -> u32 {
     let re = Regex::new(r"mul\(([1-9]\d{0,2}),([1-9]\d{0,2})\)").unwrap();
 
-    let sum = re
-        .captures_iter(input)
-        .map(|caps| {
-            let n1 = tou32(&caps[1]);
-            let n2 = tou32(&caps[2]);
-            n1 * n2
-        })
-        .sum::<u32>();
+    let sum = re.captures_iter(input).map(|caps| mul(&caps)).sum::<u32>();
     sum
 }
 
@@ -35,9 +33,7 @@ fn p2(input: &str) -> u32 {
         } else if caps[0].to_string() == "don't()" {
             enabled = false
         } else if enabled {
-            let n1 = tou32(&caps[1]);
-            let n2 = tou32(&caps[2]);
-            sum += n1 * n2
+            sum += mul(&caps)
         }
     });
     sum
@@ -47,10 +43,31 @@ fn p2(input: &str) -> u32 {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day03: mul do and don't");
-    time_it(p1, "p1", "data/03_input.txt");
-    time_it(p2, "p2", "data/03_input.txt");
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "mul do and don't";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/03_input.txt"),
+        Run::new(2, "data/2024/03_input.txt"),
+    ];
+
+    // the regexes work on the raw memory
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn p1(input: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(input)
+    }
+
+    fn p2(input: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +76,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/03_sample1.txt"), 161);
-        assert_eq!(run_it(p2, "data/03_sample2.txt"), 48);
+        assert_eq!(solve_file::<Day03>(1, "data/2024/03_sample1.txt"), "161");
+        assert_eq!(solve_file::<Day03>(2, "data/2024/03_sample2.txt"), "48");
     }
 }
@@ -0,0 +1,115 @@
+use std::convert::identity;
+
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+// part1 matrix extension
+impl Matrix<char> {
+    // check if MAS is at (x, y) in the dir
+    fn is_mas_in_dir(&self, pos: V2, dir: Dir8) -> u32 {
+        let step: V2 = dir.step();
+        bool_to_u32(
+            self.get(&(pos + step)) == Some('M')
+                && self.get(&(pos + step * 2)) == Some('A')
+                && self.get(&(pos + step * 3)) == Some('S'),
+        )
+    }
+
+    fn nb_xmas_at_point(&self, pos: V2) -> u32 {
+        // short circuit
+        if self.get(&pos) != Some('X') {
+            return 0;
+        }
+        // we check clockwise, starting from the top
+        Dir8::all().map(|dir| self.is_mas_in_dir(pos, dir)).sum()
+    }
+}
+
+fn p1(matrix: &Matrix<char>) -> u32 {
+    let mut sum = 0;
+    for y in 0..matrix.height {
+        for x in 0..matrix.width {
+            sum += matrix.nb_xmas_at_point(V2::new(x, y))
+        }
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+// part2 matrix extension
+impl Matrix<char> {
+    // check if M-S is around (x, y) in the dir
+    fn is_ms_in_dir(&self, pos: V2, dir: Dir8) -> bool {
+        self.get(&pos.add_dir(&dir)) == Some('M') && self.get(&pos.add_dir(&dir.opposite())) == Some('S')
+    }
+
+    fn is_x_dash_mas_at_point(&self, pos: V2) -> bool {
+        // short circuit
+        if self.get(&pos) != Some('A') {
+            return false;
+        }
+        // on both diagonals, M at one end and S at the other
+        [Dir8::DownRight, Dir8::DownLeft]
+            .iter()
+            .all(|&dir| self.is_ms_in_dir(pos, dir) || self.is_ms_in_dir(pos, dir.opposite()))
+    }
+}
+
+fn p2(matrix: &Matrix<char>) -> u32 {
+    let mut sum = 0;
+
+    for y in 0..matrix.height {
+        for x in 0..matrix.width {
+            sum += bool_to_u32(matrix.is_x_dash_mas_at_point(V2::new(x, y)))
+        }
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "xmas pattern";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/04_input.txt"),
+        Run::new(2, "data/2024/04_input.txt"),
+    ];
+
+    type Input = Matrix<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Matrix::try_from_str(input, identity)
+    }
+
+    fn p1(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(matrix)
+    }
+
+    fn p2(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day04>(1, "data/2024/04_sample.txt"), "18");
+        assert_eq!(solve_file::<Day04>(2, "data/2024/04_sample.txt"), "9");
+    }
+}
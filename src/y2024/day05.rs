@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+use crate::parse::*;
+use crate::solution::*;
 use crate::utils::*;
 
 type Rule = (i32, i32);
@@ -11,21 +13,23 @@ type Updates = Vec<Update>;
 // p1
 //--------------------------------------------------------------------------------
 
-fn parse_input(input: &str) -> (Rules, Updates) {
-    let (first, second) = input.split_once("\n\n").unwrap();
+fn parse_input(input: &str) -> Result<(Rules, Updates), ParseError> {
+    let (first, second) = split_once(input, "\n\n")?;
 
     let rules: Rules = first
         .lines()
-        .map(|line| line.split_once("|").unwrap())
-        .map(|(a, b)| (toi32(a), toi32(b)))
-        .collect();
+        .map(|line| {
+            let (a, b) = split_once(line, "|")?;
+            Ok((try_toi32(a)?, try_toi32(b)?))
+        })
+        .collect::<Result<_, ParseError>>()?;
 
     let updates: Updates = second
         .lines()
-        .map(|line| line.split(",").map(toi32).collect())
-        .collect();
+        .map(|line| line.split(",").map(try_toi32).collect())
+        .collect::<Result<_, ParseError>>()?;
 
-    (rules, updates)
+    Ok((rules, updates))
 }
 
 fn are_pages_in_order(rules: &Rules, p1: i32, p2: i32) -> bool {
@@ -39,12 +43,10 @@ fn is_update_valid(rules: &Rules, update: &Update) -> bool {
     })
 }
 
-fn p1(input: &str) -> i32 {
-    let (rules, updates) = parse_input(input);
-
+fn p1((rules, updates): &(Rules, Updates)) -> i32 {
     let mut sum = 0;
     for update in updates {
-        if is_update_valid(&rules, &update) {
+        if is_update_valid(rules, update) {
             sum += update[update.len() / 2];
         }
     }
@@ -65,13 +67,13 @@ fn order_pages(rules: &Rules, update: &mut Update) {
     })
 }
 
-fn p2(input: &str) -> i32 {
-    let (rules, updates) = parse_input(input);
-
+fn p2((rules, updates): &(Rules, Updates)) -> i32 {
     let mut sum = 0;
-    for mut update in updates {
-        if !is_update_valid(&rules, &update) {
-            order_pages(&rules, &mut update);
+    for update in updates {
+        if !is_update_valid(rules, update) {
+            let mut update = update.clone();
+            order_pages(rules, &mut update);
+
             sum += update[update.len() / 2];
         }
     }
@@ -82,10 +84,30 @@ fn p2(input: &str) -> i32 {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day05: print queue");
-    time_it(p1, "p1", "data/05_input.txt");
-    time_it(p2, "p2", "data/05_input.txt");
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "print queue";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/05_input.txt"),
+        Run::new(2, "data/2024/05_input.txt"),
+    ];
+
+    type Input = (Rules, Updates);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn p1(input: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(input)
+    }
+
+    fn p2(input: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
@@ -94,7 +116,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/05_sample.txt"), 143);
-        assert_eq!(run_it(p2, "data/05_sample.txt"), 123);
+        assert_eq!(solve_file::<Day05>(1, "data/2024/05_sample.txt"), "143");
+        assert_eq!(solve_file::<Day05>(2, "data/2024/05_sample.txt"), "123");
     }
 }
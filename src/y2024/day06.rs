@@ -0,0 +1,134 @@
+use std::{collections::HashSet, convert::identity};
+
+use crate::solution::*;
+use crate::utils::*;
+
+// the lab, and where the guard starts
+type Lab = (Matrix<char>, V2);
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+fn p1((matrix, start): &Lab) -> i32 {
+    let mut matrix = matrix.clone();
+
+    let mut pos = *start;
+    let mut dir = Dir::Up;
+    let mut sum = 1;
+    loop {
+        let nx = pos.add_dir(&dir);
+        match matrix.get_ref(&nx) {
+            None => break,
+            Some(&'#') => dir = dir.rot_right(),
+            Some(&'.') => {
+                pos = nx;
+                matrix.set(&pos, 'X'); // so we can remember where we've been here
+                sum += 1;
+            }
+            _ => pos = nx,
+        }
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+fn is_in_loop(matrix: &Matrix<char>, start: V2) -> bool {
+    let mut pos = start;
+    let mut times_at_pos = HashSet::new();
+    let mut dir = Dir::Up;
+    // a timed out part gives up (what it returns is thrown away)
+    while !cancelled() {
+        // turns count too: walled in on all sides, the guard only spins
+        if !times_at_pos.insert((pos, dir)) {
+            return true;
+        }
+        let nx = pos.add_dir(&dir);
+        match matrix.get_ref(&nx) {
+            None => return false,
+            Some(&'#') | Some(&'O') => dir = dir.rot_right(),
+            _ => pos = nx,
+        }
+    }
+    false
+}
+
+fn p2((matrix, start): &Lab) -> i32 {
+    let mut matrix = matrix.clone();
+
+    let start = *start;
+    let mut sum = 0;
+
+    let mut dir = Dir::Up;
+    let mut pos = start;
+
+    while !cancelled() {
+        let nx = pos.add_dir(&dir);
+        match matrix.get_ref(&nx) {
+            None => break,
+            Some(&'#') => dir = dir.rot_right(),
+            Some(&'.') => {
+                // let's try to put an obstacle here and see if we are in a loop...
+                pos = nx;
+                matrix.set(&pos, 'O');
+                if is_in_loop(&matrix, start) {
+                    sum += 1;
+                }
+                matrix.set(&pos, 'X'); // so we can remember where we've been here
+            }
+            _ => pos = nx,
+        }
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "guard and obstacle";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/06_input.txt"),
+        Run::new(2, "data/2024/06_input.txt"), // takes 20s... (1s in release mode)
+    ];
+
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let matrix = Matrix::try_from_str(input, identity)?;
+        let start = matrix.find_marker('^', input, "a guard '^'")?;
+        Ok((matrix, start))
+    }
+
+    fn p1(lab: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(lab)
+    }
+
+    fn p2(lab: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(lab)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day06>(1, "data/2024/06_sample.txt"), "41");
+        assert_eq!(solve_file::<Day06>(2, "data/2024/06_sample.txt"), "6");
+
+        // walled in on 3 sides: with an obstacle below, the guard only turns on the spot, which is
+        // a loop too (only moves were recorded before, and this never ended)
+        let lab = Day06::parse(".#.\n#^#\n...").unwrap();
+        assert_eq!((p1(&lab), p2(&lab)), (2, 1));
+    }
+}
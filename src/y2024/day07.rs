@@ -0,0 +1,116 @@
+use crate::parse::*;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (lhs, rhs) = split_once(line, ": ")?;
+            Ok((
+                try_toi64(lhs)?,
+                rhs.split(" ").map(try_toi64).collect::<Result<_, _>>()?,
+            ))
+        })
+        .collect()
+}
+
+fn p1_is_equation_valid(total: i64, lst: &[i64]) -> bool {
+    let mut nbs = vec![lst[0]];
+
+    for nx in lst.iter().skip(1) {
+        nbs = nbs.iter().flat_map(|acc| vec![acc + nx, acc * nx]).collect();
+    }
+    nbs.contains(&total)
+}
+
+fn p1(equations: &[(i64, Vec<i64>)]) -> i64 {
+    let mut sum = 0;
+    for (total, lst) in equations {
+        if p1_is_equation_valid(*total, lst) {
+            sum += total;
+        }
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+// the digits of a followed by the digits of b
+fn concat(a: i64, b: i64) -> i64 {
+    a * 10i64.pow(b.checked_ilog10().unwrap_or(0) + 1) + b
+}
+
+fn p2_is_equation_valid(total: i64, lst: &[i64]) -> bool {
+    let mut nbs = vec![lst[0]];
+
+    for nx in lst.iter().skip(1) {
+        nbs = nbs
+            .iter()
+            .flat_map(|acc| vec![acc + nx, acc * nx, concat(*acc, *nx)])
+            .collect();
+    }
+    for &acc in nbs.iter() {
+        if acc == total {
+            return true;
+        }
+    }
+    false
+}
+
+fn p2(equations: &[(i64, Vec<i64>)]) -> i64 {
+    let mut sum = 0;
+    for (total, lst) in equations.iter().take_while(|_| !cancelled()) {
+        if p2_is_equation_valid(*total, lst) {
+            sum += total;
+        }
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// run
+//--------------------------------------------------------------------------------
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "RPN calculator";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/07_input.txt"),
+        Run::new(2, "data/2024/07_input.txt"), // takes 3s
+    ];
+
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn p1(equations: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(equations)
+    }
+
+    fn p2(equations: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(equations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day07>(1, "data/2024/07_sample.txt"), "3749");
+        assert_eq!(solve_file::<Day07>(2, "data/2024/07_sample.txt"), "11387");
+    }
+}
@@ -4,6 +4,7 @@ use std::{
     convert::identity,
 };
 
+use crate::solution::*;
 use crate::utils::*;
 
 // find antennas: we build a hahsmap: antenna (char) -> list of positions (vec<V2>)
@@ -38,18 +39,17 @@ fn get_pairs(positions: &[V2]) -> Vec<(V2, V2)> {
 // p1
 //--------------------------------------------------------------------------------
 
-fn p1(input: &str) -> usize {
-    let matrix = Matrix::from_str(input, identity);
-    let antennas = get_antennas(&matrix);
+fn p1(matrix: &Matrix<char>) -> usize {
+    let antennas = get_antennas(matrix);
 
     let mut antinodes: HashSet<V2> = HashSet::new();
     for (_antenna, positions) in antennas.iter() {
         let pairs: Vec<(V2, V2)> = get_pairs(positions);
 
         for (a1, a2) in pairs {
-            let diff = a2.sub(&a1);
-            let an1 = a1.sub(&diff);
-            let an2 = a2.add(&diff);
+            let diff = a2 - a1;
+            let an1 = a1 - diff;
+            let an2 = a2 + diff;
             if matrix.is_in(&an1) {
                 antinodes.insert(an1);
             }
@@ -66,26 +66,25 @@ fn p1(input: &str) -> usize {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str) -> usize {
-    let matrix = Matrix::from_str(input, identity);
-    let antennas = get_antennas(&matrix);
+fn p2(matrix: &Matrix<char>) -> usize {
+    let antennas = get_antennas(matrix);
 
     let mut antinodes: HashSet<V2> = HashSet::new();
     for (_antenna, positions) in antennas.iter() {
         let pairs: Vec<(V2, V2)> = get_pairs(positions);
 
         for (a1, a2) in pairs {
-            let diff = a2.sub(&a1);
+            let diff = a2 - a1;
 
             let mut p = a1;
             while matrix.is_in(&p) {
                 antinodes.insert(p);
-                p = p.sub(&diff);
+                p -= diff;
             }
             p = a2;
             while matrix.is_in(&p) {
                 antinodes.insert(p);
-                p = p.add(&diff);
+                p += diff;
             }
         }
     }
@@ -97,10 +96,30 @@ fn p2(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day08: antennas");
-    time_it(p1, "p1", "data/08_input.txt");
-    time_it(p2, "p2", "data/08_input.txt");
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "antennas";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/08_input.txt"),
+        Run::new(2, "data/2024/08_input.txt"),
+    ];
+
+    type Input = Matrix<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Matrix::try_from_str(input, identity)
+    }
+
+    fn p1(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(matrix)
+    }
+
+    fn p2(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(matrix)
+    }
 }
 
 #[cfg(test)]
@@ -109,7 +128,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/08_sample.txt"), 14);
-        assert_eq!(run_it(p2, "data/08_sample.txt"), 34);
+        assert_eq!(solve_file::<Day08>(1, "data/2024/08_sample.txt"), "14");
+        assert_eq!(solve_file::<Day08>(2, "data/2024/08_sample.txt"), "34");
     }
 }
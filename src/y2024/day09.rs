@@ -1,9 +1,11 @@
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
 // p1
 //--------------------------------------------------------------------------------
 
+#[allow(dead_code)] // only used when debugging
 fn pp_blocks(dm: &[Option<u32>]) {
     for &block in dm {
         match block {
@@ -66,9 +68,8 @@ fn checksum(blocks: &[Option<u32>]) -> usize {
     sum
 }
 
-fn p1(input: &str) -> usize {
-    let disk_map = input.chars().map(c_tou32).collect::<Vec<u32>>();
-    let blocks = diskmap_to_blocks(&disk_map);
+fn p1(disk_map: &[u32]) -> usize {
+    let blocks = diskmap_to_blocks(disk_map);
     // pp_blocks(&blocks);
     let compacted = compact_blocks_p1(&blocks);
     // pp_blocks(&compacted);
@@ -124,9 +125,9 @@ fn compact_blocks_p2(blocks: &[Option<u32>]) -> Vec<Option<u32>> {
     compacted.to_vec()
 }
 
-fn p2(input: &str) -> usize {
-    let disk_map = input.chars().map(c_tou32).collect::<Vec<u32>>();
-    let blocks = diskmap_to_blocks(&disk_map);
+fn p2(disk_map: &[u32]) -> usize {
+    let blocks = diskmap_to_blocks(disk_map);
+
     // pp_blocks(&blocks);
     let compacted = compact_blocks_p2(&blocks);
     // pp_blocks(&compacted);
@@ -137,10 +138,30 @@ fn p2(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day09: compaction");
-    time_it(p1, "p1", "data/09_input.txt");
-    time_it(p2, "p2", "data/09_input.txt");
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "compaction";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/09_input.txt"),
+        Run::new(2, "data/2024/09_input.txt"),
+    ];
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.chars().map(try_c_tou32).collect()
+    }
+
+    fn p1(disk_map: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(disk_map)
+    }
+
+    fn p2(disk_map: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(disk_map)
+    }
 }
 
 #[cfg(test)]
@@ -149,7 +170,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/09_sample.txt"), 1928);
-        assert_eq!(run_it(p2, "data/09_sample.txt"), 2858);
+        assert_eq!(solve_file::<Day09>(1, "data/2024/09_sample.txt"), "1928");
+        assert_eq!(solve_file::<Day09>(2, "data/2024/09_sample.txt"), "2858");
     }
 }
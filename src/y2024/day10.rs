@@ -0,0 +1,121 @@
+use crate::search::*;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+impl Matrix<i32> {
+    // find all the starting points
+    fn get_starts(&self) -> Vec<V2> {
+        let mut starts = vec![];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.get(&V2::new(x, y)) == Some(0) {
+                    starts.push(V2::new(x, y));
+                }
+            }
+        }
+        starts
+    }
+
+    // for a given pos, find the next candidate positions
+    fn get_nxs(&self, pos: &V2) -> Vec<V2> {
+        let mut res = vec![];
+        let val = self.get(pos).unwrap();
+        for nx in self.neighbors(pos) {
+            if self.get(&nx).unwrap() == val + 1 {
+                res.push(nx);
+            }
+        }
+        res
+    }
+
+    // the score is the number of 9s reachable from start, the rating the number of trails to them.
+    // all the trails to a 9 have 9 steps, so they are all shortest paths
+    fn nb_trails_for_start(&self, start: V2, with_ratings: bool) -> usize {
+        let paths = all_shortest_paths(start, |pos| self.get_nxs(pos).into_iter().map(|nx| (nx, 1)));
+        let ends = paths.costs.keys().filter(|pos| self.get_ref(pos) == Some(&9));
+        match with_ratings {
+            true => ends.map(|end| paths.nb_paths(end)).sum(),
+            false => ends.count(),
+        }
+    }
+}
+
+fn convert(c: char) -> Option<i32> {
+    match c {
+        '.' => Some(-1),
+        _ => c.to_digit(10).map(|d| d as i32),
+    }
+}
+
+fn p1(matrix: &Matrix<i32>) -> usize {
+    let mut nb_trails = 0;
+    for start in &matrix.get_starts() {
+        nb_trails += matrix.nb_trails_for_start(*start, false);
+    }
+    nb_trails
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+fn p2(matrix: &Matrix<i32>) -> usize {
+    let mut nb_trails = 0;
+
+    for start in &matrix.get_starts() {
+        nb_trails += matrix.nb_trails_for_start(*start, true);
+    }
+    nb_trails
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "hiking the trails";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/10_input.txt"),
+        Run::new(2, "data/2024/10_input.txt"),
+    ];
+
+    type Input = Matrix<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Matrix::try_from_str_with(input, convert, "a height or '.'")
+    }
+
+    fn p1(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(matrix)
+    }
+
+    fn p2(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day10>(1, "data/2024/10_sample1a.txt"), "1");
+        assert_eq!(solve_file::<Day10>(1, "data/2024/10_sample1b.txt"), "2");
+        assert_eq!(solve_file::<Day10>(1, "data/2024/10_sample1c.txt"), "4");
+        assert_eq!(solve_file::<Day10>(1, "data/2024/10_sample1d.txt"), "3");
+        assert_eq!(solve_file::<Day10>(1, "data/2024/10_sample1e.txt"), "36");
+        assert_eq!(solve_file::<Day10>(2, "data/2024/10_sample2a.txt"), "3");
+        assert_eq!(solve_file::<Day10>(2, "data/2024/10_sample2b.txt"), "13");
+        assert_eq!(solve_file::<Day10>(2, "data/2024/10_sample2c.txt"), "227");
+        assert_eq!(solve_file::<Day10>(2, "data/2024/10_sample1e.txt"), "81");
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
@@ -18,7 +19,7 @@ fn change_stones(stones_map: &HashMap<u64, usize>) -> HashMap<u64, usize> {
             inc_value_by_count(&mut res, 1, count);
         } else {
             let nb_digits = stone.ilog10() as usize + 1;
-            if nb_digits % 2 == 0 {
+            if nb_digits.is_multiple_of(2) {
                 let p = 10_u64.pow(nb_digits as u32 / 2);
                 let right = stone % p;
                 let left = stone / p;
@@ -33,7 +34,7 @@ fn change_stones(stones_map: &HashMap<u64, usize>) -> HashMap<u64, usize> {
     res
 }
 
-fn iter_n_times(stones: Vec<u64>, n: i32) -> usize {
+fn iter_n_times(stones: &[u64], n: i32) -> usize {
     let mut stones_map = stones.iter().map(|&stone| (stone, 1)).collect::<HashMap<_, _>>();
 
     for _ in 0..n {
@@ -42,8 +43,7 @@ fn iter_n_times(stones: Vec<u64>, n: i32) -> usize {
     stones_map.values().sum::<usize>()
 }
 
-fn p1(input: &str) -> usize {
-    let stones = input.split_whitespace().map(tou64).collect::<Vec<_>>();
+fn p1(stones: &[u64]) -> usize {
     iter_n_times(stones, 25)
 }
 
@@ -51,8 +51,7 @@ fn p1(input: &str) -> usize {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str) -> usize {
-    let stones = input.split_whitespace().map(tou64).collect::<Vec<_>>();
+fn p2(stones: &[u64]) -> usize {
     iter_n_times(stones, 75)
 }
 
@@ -60,10 +59,30 @@ fn p2(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day11: blinking stones");
-    time_it(p1, "p1", "data/11_input.txt");
-    time_it(p2, "p2", "data/11_input.txt"); // takes a few seconds
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "blinking stones";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/11_input.txt"),
+        Run::new(2, "data/2024/11_input.txt"), // takes a few seconds
+    ];
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.split_whitespace().map(try_tou64).collect()
+    }
+
+    fn p1(stones: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(stones)
+    }
+
+    fn p2(stones: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(stones)
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +91,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/11_sample.txt"), 55312);
-        assert_eq!(run_it(p2, "data/11_sample.txt"), 65601038650482);
+        assert_eq!(solve_file::<Day11>(1, "data/2024/11_sample.txt"), "55312");
+        assert_eq!(solve_file::<Day11>(2, "data/2024/11_sample.txt"), "65601038650482");
     }
 }
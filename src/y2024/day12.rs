@@ -1,5 +1,6 @@
 use std::{collections::HashSet, convert::identity};
 
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
@@ -46,10 +47,10 @@ fn get_perimeter_for_region(region: &HashSet<V2>) -> usize {
     let mut sides = HashSet::new();
 
     // we scale the region by 2 and put the fences in the 'middle'
-    let scaled_region = region.iter().map(|pos| pos.scale(2)).collect::<HashSet<_>>();
+    let scaled_region = region.iter().map(|&pos| pos * 2).collect::<HashSet<_>>();
     for pos in scaled_region {
         for dir in [V2::UP, V2::DOWN, V2::LEFT, V2::RIGHT] {
-            let side = pos.add(&dir);
+            let side = pos + dir;
             if sides.contains(&side) {
                 sides.remove(&side);
             } else {
@@ -61,9 +62,8 @@ fn get_perimeter_for_region(region: &HashSet<V2>) -> usize {
     sides.len()
 }
 
-fn p1(input: &str) -> usize {
-    let matrix = Matrix::from_str(input, identity);
-    let regions = find_regions(&matrix);
+fn p1(matrix: &Matrix<char>) -> usize {
+    let regions = find_regions(matrix);
     let mut sum = 0;
     for (_c, region) in &regions {
         sum += get_area_for_region(region) * get_perimeter_for_region(region);
@@ -77,22 +77,22 @@ fn p1(input: &str) -> usize {
 
 // true if a fence is on top of the region
 fn is_top_fence(pos: &V2, scaled_region: &HashSet<V2>) -> bool {
-    scaled_region.contains(&pos.add(&V2::DOWN))
+    scaled_region.contains(&(*pos + V2::DOWN))
 }
 
 // true if a fence is on the left of the region
 fn is_left_fence(pos: &V2, scaled_region: &HashSet<V2>) -> bool {
-    scaled_region.contains(&pos.add(&V2::RIGHT))
+    scaled_region.contains(&(*pos + V2::RIGHT))
 }
 
 fn get_nb_sides_for_region(region: &HashSet<V2>) -> usize {
     let mut sides = HashSet::new();
 
     // we scale the region by 2 and put the fences in the 'middle'
-    let scaled_region = region.iter().map(|pos| pos.scale(2)).collect::<HashSet<_>>();
+    let scaled_region = region.iter().map(|&pos| pos * 2).collect::<HashSet<_>>();
     for pos in scaled_region.clone() {
         for dir in [V2::UP, V2::DOWN, V2::LEFT, V2::RIGHT] {
-            let side = pos.add(&dir);
+            let side = pos + dir;
             if sides.contains(&side) {
                 sides.remove(&side);
             } else {
@@ -106,7 +106,7 @@ fn get_nb_sides_for_region(region: &HashSet<V2>) -> usize {
     for side in sides.clone() {
         if side.x % 2 == 0 {
             // horizontal
-            let right = side.add(&V2::RIGHT.scale(2));
+            let right = side + V2::RIGHT * 2;
             let is_top_fence1 = bool_to_u32(is_top_fence(&side, &scaled_region));
             let is_top_fence2 = bool_to_u32(is_top_fence(&right, &scaled_region));
             if sides.contains(&right) && (is_top_fence1 == is_top_fence2) {
@@ -114,7 +114,7 @@ fn get_nb_sides_for_region(region: &HashSet<V2>) -> usize {
             }
         } else {
             // vertical
-            let down = side.add(&V2::DOWN.scale(2));
+            let down = side + V2::DOWN * 2;
             let is_left_fence1 = bool_to_u32(is_left_fence(&side, &scaled_region));
             let is_left_fence2 = bool_to_u32(is_left_fence(&down, &scaled_region));
             if sides.contains(&down) && (is_left_fence1 == is_left_fence2) {
@@ -125,9 +125,9 @@ fn get_nb_sides_for_region(region: &HashSet<V2>) -> usize {
     sides.len() - discarded.len()
 }
 
-fn p2(input: &str) -> usize {
-    let matrix = Matrix::from_str(input, identity);
-    let regions = find_regions(&matrix);
+fn p2(matrix: &Matrix<char>) -> usize {
+    let regions = find_regions(matrix);
+
     let mut sum = 0;
     for (_c, region) in &regions {
         sum += get_area_for_region(region) * get_nb_sides_for_region(region);
@@ -139,10 +139,30 @@ fn p2(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day12: Garden Groups");
-    time_it(p1, "p1", "data/12_input.txt");
-    time_it(p2, "p2", "data/12_input.txt");
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Garden Groups";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/12_input.txt"),
+        Run::new(2, "data/2024/12_input.txt"),
+    ];
+
+    type Input = Matrix<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Matrix::try_from_str(input, identity)
+    }
+
+    fn p1(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(matrix)
+    }
+
+    fn p2(matrix: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(matrix)
+    }
 }
 
 #[cfg(test)]
@@ -151,13 +171,13 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/12_sample1a.txt"), 140);
-        assert_eq!(run_it(p1, "data/12_sample1b.txt"), 772);
-        assert_eq!(run_it(p1, "data/12_sample1c.txt"), 1930);
-        assert_eq!(run_it(p2, "data/12_sample1a.txt"), 80);
-        assert_eq!(run_it(p2, "data/12_sample1b.txt"), 436);
-        assert_eq!(run_it(p2, "data/12_sample1c.txt"), 1206);
-        assert_eq!(run_it(p2, "data/12_sample2a.txt"), 236);
-        assert_eq!(run_it(p2, "data/12_sample2b.txt"), 368);
+        assert_eq!(solve_file::<Day12>(1, "data/2024/12_sample1a.txt"), "140");
+        assert_eq!(solve_file::<Day12>(1, "data/2024/12_sample1b.txt"), "772");
+        assert_eq!(solve_file::<Day12>(1, "data/2024/12_sample1c.txt"), "1930");
+        assert_eq!(solve_file::<Day12>(2, "data/2024/12_sample1a.txt"), "80");
+        assert_eq!(solve_file::<Day12>(2, "data/2024/12_sample1b.txt"), "436");
+        assert_eq!(solve_file::<Day12>(2, "data/2024/12_sample1c.txt"), "1206");
+        assert_eq!(solve_file::<Day12>(2, "data/2024/12_sample2a.txt"), "236");
+        assert_eq!(solve_file::<Day12>(2, "data/2024/12_sample2b.txt"), "368");
     }
 }
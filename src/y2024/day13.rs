@@ -0,0 +1,115 @@
+use crate::parse::*;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Machine {
+    btn_a: V2<i64>,
+    btn_b: V2<i64>,
+    prize: V2<i64>,
+}
+
+impl Machine {
+    pub fn from_str(s: &str) -> Result<Machine, ParseError> {
+        let mut lines = s.lines();
+        let btn_a = parse_dirs(next_line(&mut lines, s, "'Button A: ...'")?, "Button A: ", '+')?;
+        let btn_b = parse_dirs(next_line(&mut lines, s, "'Button B: ...'")?, "Button B: ", '+')?;
+        let prize = parse_dirs(next_line(&mut lines, s, "'Prize: ...'")?, "Prize: ", '=')?;
+        Ok(Machine { btn_a, btn_b, prize })
+    }
+
+    pub fn compute_cost(&self, offset: i64, enforce_limit: bool) -> i64 {
+        // cramer's rule: nb_a * btn_a + nb_b * btn_b = prize
+        let prize = self.prize + V2::new(offset, offset);
+        let det = self.btn_a.cross(&self.btn_b);
+        let num_a = prize.cross(&self.btn_b);
+        let num_b = self.btn_a.cross(&prize);
+        if num_a % det != 0 || num_b % det != 0 {
+            return 0;
+        }
+        let nb_a = num_a / det;
+        let nb_b = num_b / det;
+        if enforce_limit && (nb_a > 100 || nb_b > 100) {
+            return 0;
+        }
+        nb_a * 3 + nb_b
+    }
+}
+
+// parse directions: "Button A: X+26, Y+66" -> (26,66) (the prize uses '=' instead of '+')
+fn parse_dirs(line: &str, prefix: &str, sign: char) -> Result<V2<i64>, ParseError> {
+    let dirs = strip_prefix(line, prefix)?;
+    let (x_dir, y_dir) = split_once(dirs, ", ")?;
+    let x = strip_prefix(x_dir, &format!("X{}", sign))?;
+    let y = strip_prefix(y_dir, &format!("Y{}", sign))?;
+    Ok(V2::new(try_toi64(x)?, try_toi64(y)?))
+}
+
+fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input.split("\n\n").map(Machine::from_str).collect()
+}
+
+fn p1(machines: &[Machine]) -> i64 {
+    let mut sum = 0;
+    for machine in machines {
+        sum += machine.compute_cost(0, true);
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+fn p2(machines: &[Machine]) -> i64 {
+    let mut sum = 0;
+    for machine in machines {
+        sum += machine.compute_cost(10000000000000_i64, false);
+    }
+    sum
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/13_input.txt"),
+        Run::new(2, "data/2024/13_input.txt"),
+    ];
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_machines(input)
+    }
+
+    fn p1(machines: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(machines)
+    }
+
+    fn p2(machines: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day13>(1, "data/2024/13_sample.txt"), "480");
+        assert_eq!(solve_file::<Day13>(2, "data/2024/13_sample.txt"), "875318608908");
+    }
+}
@@ -1,5 +1,5 @@
-use regex::Regex;
-
+use crate::parse::*;
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
@@ -7,30 +7,29 @@ use crate::utils::*;
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-struct Robot {
+pub struct Robot {
     p: V2,
     v: V2,
 }
 
 impl Robot {
-    pub fn from_str(s: &str) -> Robot {
-        let re = Regex::new(r"p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap();
-        let (_, [px, py, vx, vy]) = re.captures(s).unwrap().extract();
-        Robot {
-            p: V2::new(toi32(px), toi32(py)),
-            v: V2::new(toi32(vx), toi32(vy)),
-        }
+    pub fn from_str(s: &str) -> Result<Robot, ParseError> {
+        let (p, v) = split_once(s, " v=")?;
+        Ok(Robot {
+            p: strip_prefix(p, "p=")?.parse()?,
+            v: v.parse()?,
+        })
     }
 
     pub fn step(&self, area: &V2) -> Robot {
         Robot {
-            p: self.p.add(&self.v).modulo(area),
+            p: (self.p + self.v) % *area,
             v: self.v,
         }
     }
 }
 
-fn parse_robots(input: &str) -> Vec<Robot> {
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
     input.lines().map(Robot::from_str).collect()
 }
 
@@ -38,8 +37,8 @@ fn step_robots(robots: &[Robot], area: &V2) -> Vec<Robot> {
     robots.iter().map(|robot| robot.step(area)).collect()
 }
 
-fn p1(area: V2, input: &str) -> i32 {
-    let mut robots = parse_robots(input);
+fn p1(robots: &[Robot], area: V2) -> i32 {
+    let mut robots = robots.to_vec();
     for _ in 0..100 {
         robots = step_robots(&robots, &area);
     }
@@ -87,7 +86,8 @@ fn contains_line(robots: &[Robot], area: &V2) -> bool {
     false
 }
 
-fn p2(area: V2, input: &str) -> i32 {
+// none if no tree shows up in 10000 seconds
+fn p2(robots: &[Robot], area: V2) -> Option<i32> {
     // part 2 was tricky...
     //   this is what I did:
     //   basically, I ran it 10000 times, outputted everthing in a file,
@@ -95,25 +95,46 @@ fn p2(area: V2, input: &str) -> i32 {
     //     cargo r | grep -B 100 XXXXXXXXXXXXXXXX | less
 
     // now programatically
-    let mut robots = parse_robots(input);
-    for i in 0..10000 {
+    let mut robots = robots.to_vec();
+    for i in (0..10000).take_while(|_| !cancelled()) {
         if contains_line(&robots, &area) {
-            return i;
+            return Some(i);
         }
         robots = step_robots(&robots, &area);
     }
-    -1
+    None
 }
 
 //--------------------------------------------------------------------------------
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day14: Restroom Redoubt");
-    time_it(|input| p1(V2::new(11, 7), input), "p1", "data/14_sample.txt");
-    time_it(|input| p1(V2::new(101, 103), input), "p1", "data/14_input.txt");
-    time_it(|input| p2(V2::new(101, 103), input), "p1", "data/14_input.txt");
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+    const PARAMS: &'static [Param] = &[Param::of::<i32>("width", "101"), Param::of::<i32>("height", "103")];
+    const RUNS: &'static [Run] = &[
+        Run::with(1, "data/2024/14_sample.txt", &[("width", "11"), ("height", "7")]),
+        Run::new(1, "data/2024/14_input.txt"),
+        Run::new(2, "data/2024/14_input.txt"),
+    ];
+
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_robots(input)
+    }
+
+    fn p1(robots: &Self::Input, params: &Params) -> impl Into<Answer> {
+        p1(robots, V2::new(params.get("width"), params.get("height")))
+    }
+
+    fn p2(robots: &Self::Input, params: &Params) -> impl Into<Answer> {
+        p2(robots, V2::new(params.get("width"), params.get("height")))
+    }
 }
 
 #[cfg(test)]
@@ -122,7 +143,10 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(|input| p1(V2::new(11, 7), input), "data/14_sample.txt"), 12);
-        assert_eq!(run_it(|input| p2(V2::new(101, 103), input), "data/14_input.txt"), 6516);
+        assert_eq!(solve_file::<Day14>(1, "data/2024/14_sample.txt"), "12");
+        assert_eq!(solve_file::<Day14>(2, "data/2024/14_input.txt"), "6516");
+        // a lone robot never draws a tree
+        let robots = parse_robots("p=0,0 v=1,1").unwrap();
+        assert_eq!(p2(&robots, V2::new(11, 7)), None);
     }
 }
@@ -1,44 +1,68 @@
 use std::convert::identity;
 
+use crate::parse::*;
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
 // p1
 //--------------------------------------------------------------------------------
 
-fn char_dir_to_v2(c: char) -> V2 {
+fn char_dir_to_v2(c: char) -> Option<V2> {
     match c {
-        '^' => V2::UP,
-        'v' => V2::DOWN,
-        '<' => V2::LEFT,
-        '>' => V2::RIGHT,
-        _ => panic!("invalid char dir"),
+        '^' => Some(V2::UP),
+        'v' => Some(V2::DOWN),
+        '<' => Some(V2::LEFT),
+        '>' => Some(V2::RIGHT),
+        _ => None,
     }
 }
 
+#[allow(dead_code)] // only used when debugging
 fn pp_with_robot(matrix: &mut Matrix<char>, robot: V2) {
     matrix.set(&robot, '@');
     println!("{}", matrix);
     matrix.set(&robot, '.');
 }
 
-fn p1(input: &str) -> i32 {
-    let (map, dirs) = input.split_once("\n\n").unwrap();
-    let dirs = dirs.lines().collect::<Vec<_>>().join("");
-    let mut matrix = Matrix::from_str(map, identity);
-    let mut robot = matrix.find_first('@').unwrap();
-    matrix.set(&robot, '.');
+// the robot is taken off the map, p2 widens the map
+pub struct Warehouse {
+    map: Matrix<char>,
+    robot: V2,
+    dirs: Vec<V2>,
+}
+
+fn parse_warehouse(input: &str) -> Result<Warehouse, ParseError> {
+    let (map, dirs) = split_once(input, "\n\n")?;
+    let mut map = Matrix::try_from_str(map, identity)?;
+    let robot = map.find_marker('@', input, "a robot '@'")?;
+    map.set(&robot, '.');
+    let mut moves = vec![];
+    for line in dirs.lines() {
+        for (i, c) in line.char_indices() {
+            moves.push(char_dir_to_v2(c).ok_or_else(|| ParseError::new(&line[i..i + c.len_utf8()], "a move"))?);
+        }
+    }
+    Ok(Warehouse {
+        map,
+        robot,
+        dirs: moves,
+    })
+}
 
-    for c in dirs.chars() {
-        let dir = char_dir_to_v2(c);
-        let nx = robot.add(&dir);
+fn p1(warehouse: &Warehouse) -> i32 {
+    let mut matrix = warehouse.map.clone();
+    let mut robot = warehouse.robot;
+
+    for &dir in &warehouse.dirs {
+        let nx = robot + dir;
         match matrix.get(&nx) {
             Some('.') => robot = nx,
             Some('O') => {
                 // count how many boxes we have
                 let mut nx_box = nx;
                 while matrix.get(&nx_box) == Some('O') {
-                    nx_box = nx_box.add(&dir);
+                    nx_box += dir;
                 }
                 // see if we can move them
                 if matrix.get(&nx_box) == Some('.') {
@@ -63,13 +87,13 @@ fn p1(input: &str) -> i32 {
 impl Matrix<char> {
     fn put_box_at(&mut self, pos: V2) -> &mut Self {
         self.set(&pos, '[');
-        self.set(&pos.add(&V2::RIGHT), ']');
+        self.set(&(pos + V2::RIGHT), ']');
         self
     }
 
     fn put_hori_boxes_at(&mut self, pos: V2, nb: i32) -> &mut Self {
         for i in 0..nb {
-            self.put_box_at(pos.add(&V2::RIGHT.scale(2 * i)));
+            self.put_box_at(pos + V2::RIGHT * (2 * i));
         }
         self
     }
@@ -80,8 +104,8 @@ fn can_move_boxes_in_dir(matrix: &mut Matrix<char>, box_to_check: V2, dir: V2) -
     let mut boxes_to_check = vec![box_to_check];
     while let Some(box_to_check) = boxes_to_check.pop() {
         boxes_to_move_up.push(box_to_check);
-        let up_left = box_to_check.add(&dir);
-        let up_right = up_left.add(&V2::RIGHT);
+        let up_left = box_to_check + dir;
+        let up_right = up_left + V2::RIGHT;
         match (matrix.get(&up_left), matrix.get(&up_right)) {
             (Some('.'), Some('.')) => {
                 // nothing above... we can move up
@@ -92,7 +116,7 @@ fn can_move_boxes_in_dir(matrix: &mut Matrix<char>, box_to_check: V2, dir: V2) -
             }
             (Some(']'), Some('.')) => {
                 // we have one box above, on the left
-                boxes_to_check.push(up_left.add(&V2::LEFT));
+                boxes_to_check.push(up_left + V2::LEFT);
             }
             (Some('['), Some(']')) => {
                 // we have one box right above
@@ -100,7 +124,7 @@ fn can_move_boxes_in_dir(matrix: &mut Matrix<char>, box_to_check: V2, dir: V2) -
             }
             (Some(']'), Some('[')) => {
                 // we have two boxes above
-                boxes_to_check.push(up_left.add(&V2::LEFT));
+                boxes_to_check.push(up_left + V2::LEFT);
                 boxes_to_check.push(up_right);
             }
             _ => {
@@ -117,31 +141,31 @@ fn can_move_boxes_in_dir(matrix: &mut Matrix<char>, box_to_check: V2, dir: V2) -
     }
     for b in boxes_to_move_up {
         matrix.set(&b, '.');
-        matrix.set(&b.add(&V2::RIGHT), '.');
-        matrix.set(&b.add(&dir), '[');
-        matrix.set(&b.add(&dir).add(&V2::RIGHT), ']');
+        matrix.set(&(b + V2::RIGHT), '.');
+        matrix.set(&(b + dir), '[');
+        matrix.set(&(b + dir + V2::RIGHT), ']');
     }
     true
 }
 
-fn p2(input: &str) -> i32 {
-    let (map, dirs) = input.split_once("\n\n").unwrap();
-    let dirs = dirs.lines().collect::<Vec<_>>().join("");
-    let map2 = map
-        .replace("#", "##")
-        .replace("O", "[]")
-        .replace(".", "..")
-        .replace("@", "@.");
-    let mut matrix = Matrix::from_str(&map2, identity);
-    let mut robot = matrix.find_first('@').unwrap();
-    matrix.set(&robot, '.');
+fn p2(warehouse: &Warehouse) -> i32 {
+    let rows = warehouse.map.rows().map(|row| {
+        row.iter()
+            .flat_map(|&c| match c {
+                'O' => ['[', ']'],
+                _ => [c, c],
+            })
+            .collect()
+    });
+    let mut matrix = Matrix::from_vec(rows.collect());
+    let mut robot = V2::new(warehouse.robot.x * 2, warehouse.robot.y);
 
-    for c in dirs.chars() {
+    for &dir in &warehouse.dirs {
         // pp_with_robot(&mut matrix, robot);
-        // println!("[DDA] day15:: trying to move {}", c);
-        let dir = char_dir_to_v2(c);
+        // println!("[DDA] day15:: trying to move {}", dir);
         matrix.set(&robot, '.');
-        let nx = robot.add(&dir);
+
+        let nx = robot + dir;
         match matrix.get(&nx) {
             Some('.') => {
                 robot = nx;
@@ -151,10 +175,10 @@ fn p2(input: &str) -> i32 {
                 match dir {
                     V2::LEFT => {
                         // count how many boxes we have
-                        let mut nx_box = nx.add(&dir).add(&dir);
+                        let mut nx_box = nx + dir * 2;
                         let mut nb_boxes = 1;
                         while matrix.get(&nx_box) == Some(']') {
-                            nx_box = nx_box.add(&dir).add(&dir);
+                            nx_box += dir * 2;
                             nb_boxes += 1;
                         }
                         // see if we can move them
@@ -164,16 +188,8 @@ fn p2(input: &str) -> i32 {
                             robot = nx;
                         }
                     }
-                    V2::UP => {
-                        if can_move_boxes_in_dir(&mut matrix, nx.add(&V2::LEFT), V2::UP) {
-                            robot = nx;
-                        }
-                    }
-                    V2::DOWN => {
-                        if can_move_boxes_in_dir(&mut matrix, nx.add(&V2::LEFT), V2::DOWN) {
-                            robot = nx;
-                        }
-                    }
+                    V2::UP if can_move_boxes_in_dir(&mut matrix, nx + V2::LEFT, V2::UP) => robot = nx,
+                    V2::DOWN if can_move_boxes_in_dir(&mut matrix, nx + V2::LEFT, V2::DOWN) => robot = nx,
                     _ => {}
                 }
             }
@@ -182,28 +198,20 @@ fn p2(input: &str) -> i32 {
                 match dir {
                     V2::RIGHT => {
                         // count how many boxes we have
-                        let mut nx_box = nx.add(&dir).add(&dir);
+                        let mut nx_box = nx + dir * 2;
                         let mut nb_boxes = 1;
                         while matrix.get(&nx_box) == Some('[') {
-                            nx_box = nx_box.add(&dir).add(&dir);
+                            nx_box += dir * 2;
                             nb_boxes += 1;
                         }
                         // see if we can move them
                         if matrix.get(&nx_box) == Some('.') {
-                            matrix.put_hori_boxes_at(nx.add(&dir), nb_boxes);
-                            robot = nx;
-                        }
-                    }
-                    V2::UP => {
-                        if can_move_boxes_in_dir(&mut matrix, nx, V2::UP) {
-                            robot = nx;
-                        }
-                    }
-                    V2::DOWN => {
-                        if can_move_boxes_in_dir(&mut matrix, nx, V2::DOWN) {
+                            matrix.put_hori_boxes_at(nx + dir, nb_boxes);
                             robot = nx;
                         }
                     }
+                    V2::UP if can_move_boxes_in_dir(&mut matrix, nx, V2::UP) => robot = nx,
+                    V2::DOWN if can_move_boxes_in_dir(&mut matrix, nx, V2::DOWN) => robot = nx,
                     _ => {}
                 }
             }
@@ -220,13 +228,33 @@ fn p2(input: &str) -> i32 {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day15: Warehouse Woes");
-    time_it(p1, "p1", "data/15_sample_small.txt");
-    time_it(p1, "p1", "data/15_sample.txt");
-    time_it(p1, "p1", "data/15_input.txt");
-    time_it(p2, "p2", "data/15_sample.txt");
-    time_it(p2, "p2", "data/15_input.txt");
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/15_sample_small.txt"),
+        Run::new(1, "data/2024/15_sample.txt"),
+        Run::new(1, "data/2024/15_input.txt"),
+        Run::new(2, "data/2024/15_sample.txt"),
+        Run::new(2, "data/2024/15_input.txt"),
+    ];
+
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_warehouse(input)
+    }
+
+    fn p1(warehouse: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(warehouse)
+    }
+
+    fn p2(warehouse: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(warehouse)
+    }
 }
 
 #[cfg(test)]
@@ -235,8 +263,8 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/15_sample.txt"), 10092);
-        assert_eq!(run_it(p1, "data/15_sample_small.txt"), 2028);
-        assert_eq!(run_it(p2, "data/15_sample.txt"), 9021);
+        assert_eq!(solve_file::<Day15>(1, "data/2024/15_sample.txt"), "10092");
+        assert_eq!(solve_file::<Day15>(1, "data/2024/15_sample_small.txt"), "2028");
+        assert_eq!(solve_file::<Day15>(2, "data/2024/15_sample.txt"), "9021");
     }
 }
@@ -0,0 +1,114 @@
+use std::{collections::HashSet, convert::identity};
+
+use crate::search::*;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+type Reindeer = (V2, Dir);
+
+// the maze, its start and its finish
+type Maze = (Matrix<char>, V2, V2);
+
+// a step forward costs 1, a quarter turn on the spot 1000
+fn moves(matrix: &Matrix<char>, &(pos, dir): &Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut res = vec![((pos, dir.rot_left()), 1000), ((pos, dir.rot_right()), 1000)];
+    let nx = pos.add_dir(&dir);
+    if matches!(matrix.get_ref(&nx), Some(c) if *c != '#') {
+        res.push(((nx, dir), 1));
+    }
+    res
+}
+
+// every best path from the start (facing east), and the states on the finish they end on. none
+// when the finish can't be reached
+fn best_paths(&(ref matrix, start, finish): &Maze) -> Option<(Paths<Reindeer>, Vec<Reindeer>)> {
+    let paths = all_shortest_paths((start, Dir::Right), |reindeer| moves(matrix, reindeer));
+
+    let at_finish = Dir::all()
+        .map(|dir| (finish, dir))
+        .filter(|reindeer| paths.cost(reindeer).is_some())
+        .collect::<Vec<_>>();
+    let min_score = at_finish.iter().filter_map(|reindeer| paths.cost(reindeer)).min()?;
+    let ends = at_finish
+        .into_iter()
+        .filter(|reindeer| paths.cost(reindeer) == Some(min_score))
+        .collect();
+    Some((paths, ends))
+}
+
+fn p1(maze: &Maze) -> Option<usize> {
+    let (paths, ends) = best_paths(maze)?;
+    paths.cost(ends.first()?)
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+// the tiles on any of the best paths
+fn p2(maze: &Maze) -> Option<usize> {
+    let (paths, ends) = best_paths(maze)?;
+    let seats = paths
+        .on_paths(&ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>();
+    Some(seats.len())
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/16_sample.txt"),
+        Run::new(1, "data/2024/16_sample2.txt"),
+        Run::new(1, "data/2024/16_input.txt"),
+        Run::new(2, "data/2024/16_sample.txt"),
+        Run::new(2, "data/2024/16_sample2.txt"),
+        Run::new(2, "data/2024/16_input.txt"),
+    ];
+
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let matrix = Matrix::try_from_str(input, identity)?;
+        let start = matrix.find_marker('S', input, "a start 'S'")?;
+        let finish = matrix.find_marker('E', input, "a finish 'E'")?;
+        Ok((matrix, start, finish))
+    }
+
+    fn p1(maze: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(maze)
+    }
+
+    fn p2(maze: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(maze)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day16>(1, "data/2024/16_sample.txt"), "7036");
+        assert_eq!(solve_file::<Day16>(1, "data/2024/16_sample2.txt"), "11048");
+        assert_eq!(solve_file::<Day16>(2, "data/2024/16_sample.txt"), "45");
+        assert_eq!(solve_file::<Day16>(2, "data/2024/16_sample2.txt"), "64");
+        // walled off: no path, no answer
+        let maze = Day16::parse("S#E").unwrap();
+        assert_eq!((p1(&maze), p2(&maze)), (None, None));
+    }
+}
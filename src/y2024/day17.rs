@@ -1,13 +1,20 @@
 use std::ops::BitXor;
 
+use crate::parse::*;
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
 // p1
 //--------------------------------------------------------------------------------
 
+// a / 2^n: the combo operand can be a register, so n can be 64 or more (and the bits are all gone)
+fn div(a: u64, n: u64) -> u64 {
+    a.checked_shr(n.try_into().unwrap_or(u32::MAX)).unwrap_or(0)
+}
+
 #[derive(Debug, Clone)]
-struct Machine {
+pub struct Machine {
     a: u64,
     b: u64,
     c: u64,
@@ -17,25 +24,28 @@ struct Machine {
 }
 
 impl Machine {
-    pub fn from_str(input: &str) -> Machine {
+    pub fn from_str(input: &str) -> Result<Machine, ParseError> {
         let mut lines = input.lines();
-        let line_a = lines.next().unwrap();
-        let a = tou64(line_a.split_once(": ").unwrap().1);
-        let line_b = lines.next().unwrap();
-        let b = tou64(line_b.split_once(": ").unwrap().1);
-        let line_c = lines.next().unwrap();
-        let c = tou64(line_c.split_once(": ").unwrap().1);
+        let line_a = next_line(&mut lines, input, "'Register A: ...'")?;
+        let a = try_tou64(strip_prefix(line_a, "Register A: ")?)?;
+        let line_b = next_line(&mut lines, input, "'Register B: ...'")?;
+        let b = try_tou64(strip_prefix(line_b, "Register B: ")?)?;
+        let line_c = next_line(&mut lines, input, "'Register C: ...'")?;
+        let c = try_tou64(strip_prefix(line_c, "Register C: ")?)?;
         lines.next(); // empty line
-        let line_prg = lines.next().unwrap();
-        let prg = line_prg.split_once(": ").unwrap().1.split(",").map(tou64).collect();
-        Machine {
+        let line_prg = next_line(&mut lines, input, "'Program: ...'")?;
+        let prg = strip_prefix(line_prg, "Program: ")?
+            .split(",")
+            .map(try_tou64)
+            .collect::<Result<_, _>>()?;
+        Ok(Machine {
             a,
             b,
             c,
             prg,
             pc: 0,
             out: vec![],
-        }
+        })
     }
 
     pub fn combo(&self, operand: u64) -> u64 {
@@ -55,7 +65,7 @@ impl Machine {
         match opcode {
             0 => {
                 // adv
-                self.a >>= combo_v;
+                self.a = div(self.a, combo_v);
                 self.pc += 2;
                 // println!("adv: a={}", self.a);
             }
@@ -95,13 +105,13 @@ impl Machine {
             }
             6 => {
                 // bdv
-                self.b = self.a >> combo_v;
+                self.b = div(self.a, combo_v);
                 self.pc += 2;
                 // println!("bdv: b={}", self.b);
             }
             7 => {
                 // cdv
-                self.c = self.a >> combo_v;
+                self.c = div(self.a, combo_v);
                 self.pc += 2;
                 // println!("cdv: c={}", self.c);
             }
@@ -123,8 +133,8 @@ impl Machine {
     }
 }
 
-fn p1(input: &str) -> String {
-    let mut machine = Machine::from_str(input);
+fn p1(machine: &Machine) -> String {
+    let mut machine = machine.clone();
     machine.run_prg();
     machine
         .out
@@ -162,8 +172,7 @@ fn p1(input: &str) -> String {
 //          - previous digit changes every 64 times (ratio 2^3)
 //          - previous digit changes every 8 times (ratio 2^3)
 
-fn p2(input: &str) -> u64 {
-    let machine = Machine::from_str(input);
+fn p2(machine: &Machine) -> u64 {
     let mut solution = u64::MAX;
 
     let len = machine.prg.len();
@@ -197,13 +206,33 @@ fn p2(input: &str) -> u64 {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day17: Chronospatial Computer");
-    time_it(p1, "p1", "data/17_sample.txt");
-    time_it(p1, "p1", "data/17_sample2.txt");
-    time_it(p1, "p1", "data/17_input.txt");
-    time_it(p2, "p2", "data/17_sample2.txt");
-    time_it(p2, "p2", "data/17_input.txt");
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/17_sample.txt"),
+        Run::new(1, "data/2024/17_sample2.txt"),
+        Run::new(1, "data/2024/17_input.txt"),
+        Run::new(2, "data/2024/17_sample2.txt"),
+        Run::new(2, "data/2024/17_input.txt"),
+    ];
+
+    type Input = Machine;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Machine::from_str(input)
+    }
+
+    fn p1(machine: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(machine)
+    }
+
+    fn p2(machine: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(machine)
+    }
 }
 
 #[cfg(test)]
@@ -212,9 +241,13 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/17_sample.txt"), "4,6,3,5,6,3,5,2,1,0");
-        assert_eq!(run_it(p1, "data/17_input.txt"), "4,1,5,3,1,5,3,5,7");
-        assert_eq!(run_it(p2, "data/17_sample2.txt"), 117440);
-        assert_eq!(run_it(p2, "data/17_input.txt"), 164542125272765);
+        assert_eq!(solve_file::<Day17>(1, "data/2024/17_sample.txt"), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(solve_file::<Day17>(1, "data/2024/17_input.txt"), "4,1,5,3,1,5,3,5,7");
+        assert_eq!(solve_file::<Day17>(2, "data/2024/17_sample2.txt"), "117440");
+        assert_eq!(solve_file::<Day17>(2, "data/2024/17_input.txt"), "164542125272765");
+
+        // adv with the combo operand A = 64: the shift used to overflow, all the bits are gone instead
+        let machine = Machine::from_str("Register A: 64\nRegister B: 0\nRegister C: 0\n\nProgram: 0,4,5,4").unwrap();
+        assert_eq!(p1(&machine), "0");
     }
 }
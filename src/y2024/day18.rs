@@ -0,0 +1,149 @@
+use crate::search::*;
+use crate::solution::*;
+use crate::utils::*;
+
+//--------------------------------------------------------------------------------
+// p1
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct Memory {
+    width: usize,
+    height: usize,
+    bytes: Vec<V2>,
+}
+
+impl Memory {
+    pub fn from_str(input: &str) -> Result<Memory, ParseError> {
+        let bytes = input.lines().map(str::parse).collect::<Result<Vec<V2>, _>>()?;
+        let width = bytes.iter().map(|v| v.x).max().unwrap_or(0) as usize + 1;
+        let height = bytes.iter().map(|v| v.y).max().unwrap_or(0) as usize + 1;
+
+        Ok(Memory { width, height, bytes })
+    }
+
+    pub fn to_matrix(&self, take: usize) -> Matrix<char> {
+        let mut matrix = Matrix::with_size(self.width, self.height, '.');
+
+        let mut i = 0;
+        for v in &self.bytes {
+            i += 1;
+            if i > take {
+                break;
+            }
+            matrix.set(v, '#');
+        }
+
+        matrix
+    }
+
+    // the free cells around pos
+    fn free_neighbors(matrix: &Matrix<char>, pos: &V2) -> Vec<V2> {
+        matrix
+            .neighbors(pos)
+            .into_iter()
+            .filter(|nx| matrix.get_ref(nx) == Some(&'.'))
+            .collect()
+    }
+
+    pub fn nb_steps_to_escape(&self, take: usize) -> Option<usize> {
+        let matrix = self.to_matrix(take);
+        let exit = V2::new(self.width as i32 - 1, self.height as i32 - 1);
+        let moves = |pos: &V2| Memory::free_neighbors(&matrix, pos).into_iter().map(|nx| (nx, 1));
+        let manhattan = |pos: &V2| pos.manhattan(&exit) as usize;
+        astar(V2::new(0, 0), moves, manhattan, |pos| *pos == exit).map(|(_, len)| len)
+    }
+
+    pub fn has_path_to_escape(&self, take: usize) -> bool {
+        let matrix = self.to_matrix(take);
+        let exit = V2::new(self.width as i32 - 1, self.height as i32 - 1);
+        bfs_to(
+            V2::new(0, 0),
+            |pos| Memory::free_neighbors(&matrix, pos),
+            |pos| *pos == exit,
+        )
+        .is_some()
+    }
+}
+
+fn p1(memory: &Memory, take: usize) -> Option<usize> {
+    memory.nb_steps_to_escape(take)
+}
+
+//--------------------------------------------------------------------------------
+// p2
+//--------------------------------------------------------------------------------
+
+// once the way out is cut, the next bytes can't open it again: the first byte cutting it is found
+// by bisection. none if all the bytes leave a way out
+fn p2(memory: &Memory) -> Option<V2> {
+    // a way out with `lo` bytes, none with `hi`
+    let (mut lo, mut hi) = (0, memory.bytes.len());
+    if memory.has_path_to_escape(hi) {
+        return None;
+    }
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        match memory.has_path_to_escape(mid) {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    Some(memory.bytes[hi - 1])
+}
+
+//--------------------------------------------------------------------------------
+// main
+//--------------------------------------------------------------------------------
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "RAM Run";
+    const PARAMS: &'static [Param] = &[Param::of::<usize>("take", "1024")];
+    const RUNS: &'static [Run] = &[
+        Run::with(1, "data/2024/18_sample.txt", &[("take", "12")]),
+        Run::new(1, "data/2024/18_input.txt"),
+        Run::new(2, "data/2024/18_sample.txt"),
+        Run::new(2, "data/2024/18_input.txt"),
+    ];
+
+    type Input = Memory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Memory::from_str(input)
+    }
+
+    fn p1(memory: &Self::Input, params: &Params) -> impl Into<Answer> {
+        p1(memory, params.get("take"))
+    }
+
+    fn p2(memory: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(memory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(solve_file::<Day18>(1, "data/2024/18_sample.txt"), "22");
+        assert_eq!(solve_file::<Day18>(1, "data/2024/18_input.txt"), "280");
+        assert_eq!(solve_file::<Day18>(2, "data/2024/18_sample.txt"), "6,1");
+        assert_eq!(solve_file::<Day18>(2, "data/2024/18_input.txt"), "28,56");
+        let memory = Memory::from_str("4,0\n0,1").unwrap();
+        assert_eq!((memory.width, memory.height), (5, 2));
+
+        // bytes in the corners never cut the way out, and no bytes at all neither
+        let memory = Memory::from_str("2,0\n0,2").unwrap();
+        assert_eq!((p1(&memory, 2), p2(&memory)), (Some(4), None));
+        assert_eq!(p2(&Memory::from_str("").unwrap()), None);
+        // walled in at the start
+        let memory = Memory::from_str("1,0\n0,1\n2,2").unwrap();
+        assert_eq!((p1(&memory, 2), p2(&memory)), (None, Some(V2::new(0, 1))));
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::parse::*;
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
@@ -7,18 +9,22 @@ use crate::utils::*;
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     patterns: Vec<Vec<char>>,
     designs: Vec<Vec<char>>,
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
-        let (patterns, designs) = input.split_once("\n\n").unwrap();
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
+        let (patterns, designs) = split_once(input, "\n\n")?;
+        // an empty pattern would match forever
+        if let Some(empty) = patterns.split(", ").find(|p| p.is_empty()) {
+            return Err(ParseError::new(empty, "a pattern"));
+        }
         let patterns = patterns.split(", ").map(|line| line.chars().collect()).collect();
         let designs = designs.lines().map(|line| line.chars().collect()).collect();
 
-        Puzzle { patterns, designs }
+        Ok(Puzzle { patterns, designs })
     }
 
     pub fn can_solve_for_design(&self, design: &[char]) -> bool {
@@ -51,8 +57,7 @@ impl Puzzle {
     }
 }
 
-fn p1(input: &str) -> u32 {
-    let puzzle = Puzzle::from_str(input);
+fn p1(puzzle: &Puzzle) -> u32 {
     let mut sum = 0;
     for d in &puzzle.designs {
         sum += bool_to_u32(puzzle.can_solve_for_design(d));
@@ -64,8 +69,7 @@ fn p1(input: &str) -> u32 {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str) -> u64 {
-    let puzzle = Puzzle::from_str(input);
+fn p2(puzzle: &Puzzle) -> u64 {
     let mut sum = 0;
     for d in &puzzle.designs {
         let mut cache: HashMap<Vec<char>, u64> = HashMap::new();
@@ -78,12 +82,32 @@ fn p2(input: &str) -> u64 {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day19: Linen Layout");
-    time_it(p1, "p1", "data/19_sample.txt");
-    time_it(p1, "p1", "data/19_input.txt");
-    time_it(p2, "p2", "data/19_sample.txt");
-    time_it(p2, "p2", "data/19_input.txt");
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Linen Layout";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/19_sample.txt"),
+        Run::new(1, "data/2024/19_input.txt"),
+        Run::new(2, "data/2024/19_sample.txt"),
+        Run::new(2, "data/2024/19_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(puzzle)
+    }
+
+    fn p2(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -92,9 +116,9 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/19_sample.txt"), 6);
-        assert_eq!(run_it(p1, "data/19_input.txt"), 242);
-        assert_eq!(run_it(p2, "data/19_sample.txt"), 16);
-        assert_eq!(run_it(p2, "data/19_input.txt"), 595975512785325);
+        assert_eq!(solve_file::<Day19>(1, "data/2024/19_sample.txt"), "6");
+        assert_eq!(solve_file::<Day19>(1, "data/2024/19_input.txt"), "242");
+        assert_eq!(solve_file::<Day19>(2, "data/2024/19_sample.txt"), "16");
+        assert_eq!(solve_file::<Day19>(2, "data/2024/19_input.txt"), "595975512785325");
     }
 }
@@ -1,5 +1,7 @@
 use std::{collections::HashMap, convert::identity};
 
+use crate::search::*;
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
@@ -7,19 +9,19 @@ use crate::utils::*;
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     racetrack: Matrix<char>,
     start: V2,
     end: V2,
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
-        let racetrack = Matrix::from_str(input, identity).clone_without_border();
-        let start = racetrack.find_first('S').unwrap();
-        let end = racetrack.find_first('E').unwrap();
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
+        let racetrack = Matrix::try_from_str(input, identity)?.clone_without_border();
+        let start = racetrack.find_marker('S', input, "a start 'S'")?;
+        let end = racetrack.find_marker('E', input, "an end 'E'")?;
 
-        Puzzle { racetrack, start, end }
+        Ok(Puzzle { racetrack, start, end })
     }
 
     // pub fn pp_savings(&self, savings_for_cheat: &HashMap<(V2, V2), usize>) {
@@ -35,31 +37,24 @@ impl Puzzle {
     //     }
     // }
 
-    pub fn solve_p1(&self, threshold: i32) -> usize {
-        let mut track = self.racetrack.clone();
-        let mut pos = self.start;
-        let mut cost = 0;
-        let mut path = vec![];
-
-        // basically a map of pos -> cost (ie index of pos on track)
-        let mut cost_at_pos = HashMap::new();
+    // the cells of the track before the end, in order, and the cost at each cell (1 at the start,
+    // the track has no fork so the distance from the start is the index)
+    fn walk_track(&self) -> (Vec<V2>, HashMap<V2, i32>) {
+        let dist = bfs(self.start, |pos| {
+            self.racetrack
+                .neighbors(pos)
+                .into_iter()
+                .filter(|nx| self.racetrack.get_ref(nx) != Some(&'#'))
+                .collect::<Vec<_>>()
+        });
+        let mut path = dist.keys().filter(|&&pos| pos != self.end).copied().collect::<Vec<_>>();
+        path.sort_by_key(|pos| dist[pos]);
+        let cost_at_pos = dist.into_iter().map(|(pos, d)| (pos, d as i32 + 1)).collect();
+        (path, cost_at_pos)
+    }
 
-        while pos != self.end {
-            track.set(&pos, '-');
-            cost += 1;
-            cost_at_pos.insert(pos, cost);
-            path.push(pos);
-
-            let mut nx_pos = None;
-            for nabe in track.neighbors(&pos) {
-                if track.get(&nabe) == Some('.') || track.get(&nabe) == Some('E') {
-                    nx_pos = Some(nabe);
-                    break;
-                }
-            }
-            pos = nx_pos.unwrap();
-        }
-        cost_at_pos.insert(pos, cost + 1);
+    pub fn solve_p1(&self, threshold: i32) -> usize {
+        let (path, cost_at_pos) = self.walk_track();
 
         let mut shortcuts = HashMap::new();
         let mut track = self.racetrack.clone();
@@ -68,12 +63,12 @@ impl Puzzle {
             cost += 1;
             track.set(&pos, 'X');
             for cheat1 in track.neighbors(&pos) {
-                if track.get(&cheat1) != Some('#') {
+                if track.get_ref(&cheat1) != Some(&'#') {
                     // cheat1 needs to be on a wall
                     continue;
                 }
                 for cheat2 in track.neighbors(&cheat1) {
-                    if cheat2 == pos || track.get(&cheat2) != Some('.') {
+                    if cheat2 == pos || track.get_ref(&cheat2) != Some(&'.') {
                         // cheat2 needs to be back on the path
                         continue;
                     }
@@ -96,30 +91,7 @@ impl Puzzle {
     }
 
     pub fn solve_p2(&self, threshold: i32, cheat_length: i32) -> usize {
-        let mut track = self.racetrack.clone();
-        let mut pos = self.start;
-        let mut cost = 0;
-        let mut path = vec![];
-
-        // basically a map of pos -> cost (ie index of pos on track)
-        let mut cost_at_pos = HashMap::new();
-
-        while pos != self.end {
-            track.set(&pos, '-');
-            cost += 1;
-            cost_at_pos.insert(pos, cost);
-            path.push(pos);
-
-            let mut nx_pos = None;
-            for nabe in track.neighbors(&pos) {
-                if track.get(&nabe) == Some('.') || track.get(&nabe) == Some('E') {
-                    nx_pos = Some(nabe);
-                    break;
-                }
-            }
-            pos = nx_pos.unwrap();
-        }
-        cost_at_pos.insert(pos, cost + 1);
+        let (path, cost_at_pos) = self.walk_track();
 
         let mut shortcuts = HashMap::new();
         let mut track = self.racetrack.clone();
@@ -136,16 +108,16 @@ impl Puzzle {
                     // if y < 0 || y >= track.height {
                     //     continue;
                     // }
-                    if !track.is_in(&V2::new(x, y)) {
+                    let cheat2 = V2::new(x, y);
+                    if !track.is_in(&cheat2) {
                         continue;
                     }
-                    let dist = (pos.x - x).abs() + (pos.y - y).abs();
+                    let dist = pos.manhattan(&cheat2);
                     if dist == 0 || dist > cheat_length {
                         continue;
                     }
 
-                    let cheat2 = V2::new(x, y);
-                    if cheat2 == pos || track.get(&cheat2) != Some('.') {
+                    if cheat2 == pos || track.get_ref(&cheat2) != Some(&'.') {
                         // cheat2 needs to be back on the path
                         continue;
                     }
@@ -168,8 +140,7 @@ impl Puzzle {
     }
 }
 
-fn p1(input: &str, threshold: i32) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p1(puzzle: &Puzzle, threshold: i32) -> usize {
     puzzle.solve_p1(threshold)
 }
 
@@ -177,8 +148,7 @@ fn p1(input: &str, threshold: i32) -> usize {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str, threshold: i32) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p2(puzzle: &Puzzle, threshold: i32) -> usize {
     puzzle.solve_p2(threshold, 20)
 }
 
@@ -186,12 +156,33 @@ fn p2(input: &str, threshold: i32) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day20: Race Condition");
-    time_it(|input| p1(input, 0), "p1", "data/20_sample.txt");
-    time_it(|input| p1(input, 100), "p1", "data/20_input.txt");
-    time_it(|input| p2(input, 50), "p2", "data/20_sample.txt");
-    time_it(|input| p2(input, 100), "p2", "data/20_input.txt");
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Race Condition";
+    const PARAMS: &'static [Param] = &[Param::of::<i32>("threshold", "100")];
+    const RUNS: &'static [Run] = &[
+        Run::with(1, "data/2024/20_sample.txt", &[("threshold", "0")]),
+        Run::new(1, "data/2024/20_input.txt"),
+        Run::with(2, "data/2024/20_sample.txt", &[("threshold", "50")]),
+        Run::new(2, "data/2024/20_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, params: &Params) -> impl Into<Answer> {
+        p1(puzzle, params.get("threshold"))
+    }
+
+    fn p2(puzzle: &Self::Input, params: &Params) -> impl Into<Answer> {
+        p2(puzzle, params.get("threshold"))
+    }
 }
 
 #[cfg(test)]
@@ -200,9 +191,9 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(|input| p1(input, 0), "data/20_sample.txt"), 44);
-        assert_eq!(run_it(|input| p1(input, 100), "data/20_input.txt"), 1429);
-        assert_eq!(run_it(|input| p2(input, 50), "data/20_sample.txt"), 285);
-        assert_eq!(run_it(|input| p2(input, 100), "data/20_input.txt"), 988931);
+        assert_eq!(solve_file::<Day20>(1, "data/2024/20_sample.txt"), "44");
+        assert_eq!(solve_file::<Day20>(1, "data/2024/20_input.txt"), "1429");
+        assert_eq!(solve_file::<Day20>(2, "data/2024/20_sample.txt"), "285");
+        assert_eq!(solve_file::<Day20>(2, "data/2024/20_input.txt"), "988931");
     }
 }
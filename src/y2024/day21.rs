@@ -1,6 +1,7 @@
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 
+use crate::solution::*;
 use crate::utils::*;
 
 type PathD = Vec<Dir>;
@@ -69,7 +70,7 @@ fn compute_matrix_shortest_paths(matrix: &Matrix<char>) -> HashMap<(char, char),
                     } else {
                         let mut to_eplore = vec![(p0, vec![])];
                         // we already know the cost
-                        let cost = (x0 - x1).abs() + (y0 - y1).abs();
+                        let cost = p0.manhattan(&p1);
                         while let Some((pos, path)) = to_eplore.pop() {
                             if path.len() > cost as usize {
                                 continue;
@@ -80,7 +81,7 @@ fn compute_matrix_shortest_paths(matrix: &Matrix<char>) -> HashMap<(char, char),
                                 paths.push(path_chars);
                                 continue;
                             }
-                            for dir in [Dir::Up, Dir::Down, Dir::Left, Dir::Right] {
+                            for dir in Dir::all() {
                                 let nx = pos.add_dir(&dir);
                                 if !matrix.is_in(&nx) || matrix.get(&pos) == Some(' ') {
                                     continue;
@@ -157,10 +158,11 @@ pub fn keep_one(paths: &[PathC]) -> PathC {
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
-struct Puzzle {
-    codes: Vec<Vec<char>>,
+pub struct Puzzle {
+    codes: Vec<(Vec<char>, usize)>, // the keys to type, and the numeric part of the code
 }
 
+#[allow(dead_code)] // only used when debugging
 fn pp_path(path: &PathC) {
     for i in path {
         print!("{}", i);
@@ -201,26 +203,30 @@ pub fn shortest_len(pos_arm: char, c: char, level: usize, cache: &mut HashMap<(c
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
-        let codes = input.lines().map(|line| line.chars().collect()).collect();
-        Puzzle { codes }
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
+        let mut codes = vec![];
+        for line in input.lines() {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_digit() && c != 'A') {
+                return Err(ParseError::new(&line[i..i + 1], "a digit or 'A'"));
+            }
+            let digits = line.trim_matches('A');
+            codes.push((line.chars().collect(), try_tousize(digits)?));
+        }
+        Ok(Puzzle { codes })
     }
 
     pub fn solve(&self, nb_robots: usize) -> usize {
         let mut sum = 0;
         let mut cache: HashMap<(char, char, usize), usize> = HashMap::new();
-        for code in &self.codes {
+        for (code, code_i32) in &self.codes {
             // println!("[DDA] day21:: trying to type code: {:?}", code);
 
-            let code_string: String = code.iter().filter(|c| c.is_ascii_digit()).collect();
-            let code_i32 = tousize(&code_string);
-
             // this is the path we need the final robot to type on the door
             let path = get_path_for_nums(code);
 
             let mut pos_arm = 'A';
             for c in path {
-                sum += code_i32 * shortest_len(pos_arm, c, nb_robots, &mut cache);
+                sum += *code_i32 * shortest_len(pos_arm, c, nb_robots, &mut cache);
                 pos_arm = c;
             }
         }
@@ -228,8 +234,7 @@ impl Puzzle {
     }
 }
 
-fn p1(input: &str) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p1(puzzle: &Puzzle) -> usize {
     puzzle.solve(2)
 }
 
@@ -237,8 +242,7 @@ fn p1(input: &str) -> usize {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p2(puzzle: &Puzzle) -> usize {
     puzzle.solve(25)
 }
 
@@ -246,12 +250,32 @@ fn p2(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day21: Keypad Conundrum");
-    time_it(p1, "p1", "data/21_sample.txt");
-    time_it(p1, "p1", "data/21_input.txt");
-    time_it(p2, "p2", "data/21_sample.txt");
-    time_it(p2, "p2", "data/21_input.txt");
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Keypad Conundrum";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/21_sample.txt"),
+        Run::new(1, "data/2024/21_input.txt"),
+        Run::new(2, "data/2024/21_sample.txt"),
+        Run::new(2, "data/2024/21_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(puzzle)
+    }
+
+    fn p2(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -260,9 +284,9 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/21_sample.txt"), 126384);
-        assert_eq!(run_it(p1, "data/21_input.txt"), 188398);
-        assert_eq!(run_it(p2, "data/21_sample.txt"), 154115708116294);
-        assert_eq!(run_it(p2, "data/21_input.txt"), 230049027535970);
+        assert_eq!(solve_file::<Day21>(1, "data/2024/21_sample.txt"), "126384");
+        assert_eq!(solve_file::<Day21>(1, "data/2024/21_input.txt"), "188398");
+        assert_eq!(solve_file::<Day21>(2, "data/2024/21_sample.txt"), "154115708116294");
+        assert_eq!(solve_file::<Day21>(2, "data/2024/21_input.txt"), "230049027535970");
     }
 }
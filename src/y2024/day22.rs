@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::solution::*;
 use crate::utils::*;
 
 // a sequence of 4 consecutive changes
@@ -12,7 +13,7 @@ const SEQ_NOOP: i8 = -100; // anything outside of [-9, 9] is good...
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     secret_numbers: Vec<i64>,
 }
 
@@ -37,9 +38,9 @@ fn compute_nx(secret_number: i64) -> i64 {
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
-        let secret_numbers = input.lines().map(toi64).collect();
-        Puzzle { secret_numbers }
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
+        let secret_numbers = input.lines().map(try_toi64).collect::<Result<_, _>>()?;
+        Ok(Puzzle { secret_numbers })
     }
 
     pub fn p1(&self) -> usize {
@@ -102,8 +103,7 @@ impl Puzzle {
     }
 }
 
-fn p1(input: &str) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p1(puzzle: &Puzzle) -> usize {
     puzzle.p1()
 }
 
@@ -111,8 +111,7 @@ fn p1(input: &str) -> usize {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p2(puzzle: &Puzzle) -> usize {
     puzzle.p2()
 }
 
@@ -120,12 +119,32 @@ fn p2(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day22: Keypad Conundrum");
-    time_it(p1, "p1", "data/22_sample.txt");
-    time_it(p1, "p1", "data/22_input.txt");
-    time_it(p2, "p2", "data/22_sample2.txt");
-    time_it(p2, "p2", "data/22_input.txt");
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Monkey Market";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/22_sample.txt"),
+        Run::new(1, "data/2024/22_input.txt"),
+        Run::new(2, "data/2024/22_sample2.txt"),
+        Run::new(2, "data/2024/22_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(puzzle)
+    }
+
+    fn p2(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -134,9 +153,9 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/22_sample.txt"), 37327623);
-        assert_eq!(run_it(p1, "data/22_input.txt"), 13584398738);
-        assert_eq!(run_it(p2, "data/22_sample2.txt"), 23);
-        assert_eq!(run_it(p2, "data/22_input.txt"), 1612);
+        assert_eq!(solve_file::<Day22>(1, "data/2024/22_sample.txt"), "37327623");
+        assert_eq!(solve_file::<Day22>(1, "data/2024/22_input.txt"), "13584398738");
+        assert_eq!(solve_file::<Day22>(2, "data/2024/22_sample2.txt"), "23");
+        assert_eq!(solve_file::<Day22>(2, "data/2024/22_input.txt"), "1612");
     }
 }
@@ -1,23 +1,25 @@
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-use crate::utils::*;
+use crate::parse::*;
+use crate::solution::*;
 
 //--------------------------------------------------------------------------------
 // p1
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     connections: HashSet<(usize, usize)>, // list of connections between computers
     graph: HashMap<usize, Vec<usize>>,    // for each computer, list of connected computers
 }
 
-fn comp_name_to_comp_id(comp_name: &str) -> usize {
-    let chars = comp_name.chars().collect::<Vec<_>>();
-    let c0 = chars[0];
-    let c1 = chars[1];
-    (c1 as usize) + (c0 as usize) * 256
+// names are 2 lowercase letters
+fn comp_name_to_comp_id(comp_name: &str) -> Result<usize, ParseError> {
+    match comp_name.as_bytes() {
+        &[c0, c1] if c0.is_ascii_lowercase() && c1.is_ascii_lowercase() => Ok((c1 as usize) + (c0 as usize) * 256),
+        _ => Err(ParseError::new(comp_name, "a computer name of 2 letters")),
+    }
 }
 
 fn comp_id_to_comp_name(comp_id: usize) -> String {
@@ -26,6 +28,7 @@ fn comp_id_to_comp_name(comp_id: usize) -> String {
     format!("{}{}", c0, c1)
 }
 
+#[allow(dead_code)] // only used when debugging
 fn comp_ids_to_comp_names(comp_ids: &[usize]) -> Vec<String> {
     comp_ids.iter().map(|&c| comp_id_to_comp_name(c)).collect()
 }
@@ -36,17 +39,15 @@ fn is_starting_with_t(comp_id: usize) -> bool {
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
         // connection: map (comp_id, comp_id)
-        let connections: HashSet<(usize, usize)> = input
-            .lines()
-            .flat_map(|line| {
-                let (l_str, r_str) = line.split_once("-").unwrap();
-                let l = comp_name_to_comp_id(l_str);
-                let r = comp_name_to_comp_id(r_str);
-                [(l, r), (r, l)]
-            })
-            .collect();
+        let mut connections: HashSet<(usize, usize)> = HashSet::new();
+        for line in input.lines() {
+            let (l_str, r_str) = split_once(line, "-")?;
+            let l = comp_name_to_comp_id(l_str)?;
+            let r = comp_name_to_comp_id(r_str)?;
+            connections.extend([(l, r), (r, l)]);
+        }
 
         // prepare graph
         let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
@@ -54,7 +55,7 @@ impl Puzzle {
             graph.entry(l).or_default().push(r);
         }
 
-        Puzzle { connections, graph }
+        Ok(Puzzle { connections, graph })
     }
 
     pub fn p1(&self) -> usize {
@@ -122,7 +123,7 @@ impl Puzzle {
     }
 
     pub fn p2(&self) -> String {
-        let max_connections = self.graph.values().map(|v| v.len()).max().unwrap();
+        let max_connections = self.graph.values().map(|v| v.len()).max().unwrap_or(0);
 
         for i in (2..=max_connections).rev() {
             if let Some(pw) = self.pw_for_clusters_of_size_n(i) {
@@ -133,8 +134,7 @@ impl Puzzle {
     }
 }
 
-fn p1(input: &str) -> usize {
-    let puzzle = Puzzle::from_str(input);
+fn p1(puzzle: &Puzzle) -> usize {
     puzzle.p1()
 }
 
@@ -142,8 +142,7 @@ fn p1(input: &str) -> usize {
 // p2
 //--------------------------------------------------------------------------------
 
-fn p2(input: &str) -> String {
-    let puzzle = Puzzle::from_str(input);
+fn p2(puzzle: &Puzzle) -> String {
     puzzle.p2()
 }
 
@@ -151,12 +150,32 @@ fn p2(input: &str) -> String {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day23: LAN Party");
-    time_it(p1, "p1", "data/23_sample.txt");
-    time_it(p1, "p1", "data/23_input.txt");
-    time_it(p2, "p2", "data/23_sample.txt");
-    time_it(p2, "p2", "data/23_input.txt");
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "LAN Party";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/23_sample.txt"),
+        Run::new(1, "data/2024/23_input.txt"),
+        Run::new(2, "data/2024/23_sample.txt"),
+        Run::new(2, "data/2024/23_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(puzzle)
+    }
+
+    fn p2(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -165,11 +184,11 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/23_sample.txt"), 7);
-        assert_eq!(run_it(p1, "data/23_input.txt"), 1284);
-        assert_eq!(run_it(p2, "data/23_sample.txt"), "co,de,ka,ta");
+        assert_eq!(solve_file::<Day23>(1, "data/2024/23_sample.txt"), "7");
+        assert_eq!(solve_file::<Day23>(1, "data/2024/23_input.txt"), "1284");
+        assert_eq!(solve_file::<Day23>(2, "data/2024/23_sample.txt"), "co,de,ka,ta");
         assert_eq!(
-            run_it(p2, "data/23_input.txt"),
+            solve_file::<Day23>(2, "data/2024/23_input.txt"),
             "bv,cm,dk,em,gs,jv,ml,oy,qj,ri,uo,xk,yw"
         );
     }
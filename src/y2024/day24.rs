@@ -1,11 +1,11 @@
 use core::fmt;
-use regex::Regex;
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
 };
 
-use crate::utils::*;
+use crate::parse::*;
+use crate::solution::*;
 
 //--------------------------------------------------------------------------------
 // op
@@ -27,12 +27,12 @@ impl Op {
         }
     }
 
-    pub fn from_str(s: &str) -> Op {
+    pub fn from_str(s: &str) -> Result<Op, ParseError> {
         match s {
-            "AND" => Op::And,
-            "OR" => Op::Or,
-            "XOR" => Op::Xor,
-            _ => panic!("invalid op {}", s),
+            "AND" => Ok(Op::And),
+            "OR" => Ok(Op::Or),
+            "XOR" => Ok(Op::Xor),
+            _ => Err(ParseError::new(s, "AND, OR or XOR")),
         }
     }
 }
@@ -61,6 +61,7 @@ struct Gate {
 }
 
 impl Gate {
+    #[allow(dead_code)]
     pub fn eval(&self, wires: &HashMap<String, bool>) -> bool {
         let in1 = wires.get(&self.in1).unwrap();
         let in2 = wires.get(&self.in2).unwrap();
@@ -92,52 +93,61 @@ impl Display for Gate {
 // puzzle
 //--------------------------------------------------------------------------------
 
+// wire names are letters and digits (x00, z12, kjc...)
+fn parse_wire(s: &str) -> Result<String, ParseError> {
+    match !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()) {
+        true => Ok(String::from(s)),
+        false => Err(ParseError::new(s, "a wire name")),
+    }
+}
+
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     wires: HashMap<String, bool>,
     gates: Vec<Gate>,
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
-        let (wires_str, gates_str) = input.split_once("\n\n").unwrap();
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
+        let (wires_str, gates_str) = split_once(input, "\n\n")?;
 
         // wires
         let wires: HashMap<String, bool> = wires_str
             .lines()
             .map(|line| {
-                let (w, v) = line.split_once(": ").unwrap();
-                (String::from(w), v == "1")
+                let (w, v) = split_once(line, ": ")?;
+                match v {
+                    "0" | "1" => Ok((parse_wire(w)?, v == "1")),
+                    _ => Err(ParseError::new(v, "0 or 1")),
+                }
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         // gates
         let gates = gates_str
             .lines()
             .map(|line| {
-                let re = Regex::new(r"^(\w+) (\w+) (\w+) -> (\w+)$").unwrap();
-                let (_, [in1, op, in2, out]) = re.captures(line).unwrap().extract();
-                Gate {
-                    in1: String::from(in1),
-                    in2: String::from(in2),
-                    out: String::from(out),
-                    op: Op::from_str(op),
-                }
+                let (lhs, out) = split_once(line, " -> ")?;
+                let (in1, rest) = split_once(lhs, " ")?;
+                let (op, in2) = split_once(rest, " ")?;
+                Ok(Gate {
+                    in1: parse_wire(in1)?,
+                    in2: parse_wire(in2)?,
+                    out: parse_wire(out)?,
+                    op: Op::from_str(op)?,
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Puzzle { wires, gates }
+        Ok(Puzzle { wires, gates })
     }
 
     // reset all computed wires (only keep x and y)
     pub fn reset_wires(&mut self) {
         let keys = self.wires.keys().cloned().collect::<Vec<_>>();
         for wire in keys {
-            match wire.chars().nth(0).unwrap() {
-                'x' | 'y' => {}
-                _ => {
-                    self.wires.remove(&wire);
-                }
+            if !wire.starts_with(['x', 'y']) {
+                self.wires.remove(&wire);
             }
         }
     }
@@ -220,8 +230,8 @@ impl Puzzle {
     }
 }
 
-fn p1(input: &str) -> usize {
-    let mut puzzle = Puzzle::from_str(input);
+fn p1(puzzle: &Puzzle) -> usize {
+    let mut puzzle = puzzle.clone();
 
     puzzle.eval_dfs();
     puzzle.get_var('z')
@@ -364,8 +374,8 @@ impl Puzzle {
     }
 }
 
-fn p2(input: &str) -> String {
-    let mut puzzle = Puzzle::from_str(input);
+fn p2(puzzle: &Puzzle) -> String {
+    let mut puzzle = puzzle.clone();
 
     // without swapping any wires we find issues with bits 11, 12, 25, 26, 31, 36
     //
@@ -402,12 +412,32 @@ fn p2(input: &str) -> String {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day24: Crossed Wires");
-    time_it(p1, "p1", "data/24_sample.txt");
-    time_it(p1, "p1", "data/24_sample2.txt");
-    time_it(p1, "p1", "data/24_input.txt");
-    time_it(p2, "p2", "data/24_input.txt");
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Crossed Wires";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/24_sample.txt"),
+        Run::new(1, "data/2024/24_sample2.txt"),
+        Run::new(1, "data/2024/24_input.txt"),
+        Run::new(2, "data/2024/24_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(puzzle)
+    }
+
+    fn p2(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p2(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -416,9 +446,12 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/24_sample.txt"), 4);
-        assert_eq!(run_it(p1, "data/24_sample2.txt"), 2024);
-        assert_eq!(run_it(p1, "data/24_input.txt"), 55544677167336);
-        assert_eq!(run_it(p2, "data/24_input.txt"), "gsd,kth,qnf,tbt,vpm,z12,z26,z32");
+        assert_eq!(solve_file::<Day24>(1, "data/2024/24_sample.txt"), "4");
+        assert_eq!(solve_file::<Day24>(1, "data/2024/24_sample2.txt"), "2024");
+        assert_eq!(solve_file::<Day24>(1, "data/2024/24_input.txt"), "55544677167336");
+        assert_eq!(
+            solve_file::<Day24>(2, "data/2024/24_input.txt"),
+            "gsd,kth,qnf,tbt,vpm,z12,z26,z32"
+        );
     }
 }
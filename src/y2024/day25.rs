@@ -1,5 +1,6 @@
 use std::convert::identity;
 
+use crate::solution::*;
 use crate::utils::*;
 
 //--------------------------------------------------------------------------------
@@ -7,12 +8,12 @@ use crate::utils::*;
 //--------------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
-struct Puzzle {
+pub struct Puzzle {
     keys: Vec<Vec<i32>>,
     locks: Vec<Vec<i32>>,
 }
 
-fn key_fitst_lock(key: &Vec<i32>, lock: &Vec<i32>) -> bool {
+fn key_fitst_lock(key: &[i32], lock: &[i32]) -> bool {
     for i in 0..key.len() {
         if key[i] + lock[i] >= 6 {
             return false;
@@ -22,12 +23,12 @@ fn key_fitst_lock(key: &Vec<i32>, lock: &Vec<i32>) -> bool {
 }
 
 impl Puzzle {
-    pub fn from_str(input: &str) -> Puzzle {
+    pub fn from_str(input: &str) -> Result<Puzzle, ParseError> {
         let mut keys = vec![];
         let mut locks = vec![];
         let groups = input.split("\n\n").collect::<Vec<_>>();
         for &g in &groups {
-            let m = Matrix::from_str(g, identity);
+            let m = Matrix::try_from_str(g, identity)?;
             // keys have the top row filled with '#'
             let is_lock = (0..m.width).all(|x| m.get(&V2::new(x, 0)) == Some('#'));
             if is_lock {
@@ -58,13 +59,11 @@ impl Puzzle {
                 keys.push(heights);
             }
         }
-        Puzzle { keys, locks }
+        Ok(Puzzle { keys, locks })
     }
 }
 
-fn p1(input: &str) -> usize {
-    let puzzle = Puzzle::from_str(input);
-
+fn p1(puzzle: &Puzzle) -> usize {
     let mut nb_fits = 0;
     for key in &puzzle.keys {
         for lock in &puzzle.locks {
@@ -89,10 +88,26 @@ fn p1(input: &str) -> usize {
 // main
 //--------------------------------------------------------------------------------
 
-pub fn run() {
-    pp_day("day25: Code Chronicle");
-    time_it(p1, "p1", "data/25_sample.txt");
-    time_it(p1, "p1", "data/25_input.txt");
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Code Chronicle";
+    const RUNS: &'static [Run] = &[
+        Run::new(1, "data/2024/25_sample.txt"),
+        Run::new(1, "data/2024/25_input.txt"),
+    ];
+
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::from_str(input)
+    }
+
+    fn p1(puzzle: &Self::Input, _: &Params) -> impl Into<Answer> {
+        p1(puzzle)
+    }
 }
 
 #[cfg(test)]
@@ -101,7 +116,7 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(run_it(p1, "data/25_sample.txt"), 3);
-        assert_eq!(run_it(p1, "data/25_input.txt"), 3155);
+        assert_eq!(solve_file::<Day25>(1, "data/2024/25_sample.txt"), "3");
+        assert_eq!(solve_file::<Day25>(1, "data/2024/25_input.txt"), "3155");
     }
 }
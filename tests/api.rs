@@ -0,0 +1,27 @@
+use std::convert::identity;
+
+use aoc24::solution::{read_input, Answer, Params, Solution};
+use aoc24::utils::{Matrix, V2};
+use aoc24::y2024::day06::Day06;
+
+// the library can be used without the runner: grids, a day's solver, or any day from the registry
+#[test]
+fn test() {
+    let matrix = Matrix::from_str("#.\n.^", identity);
+    assert_eq!(matrix.find_first('^'), Some(V2::new(1, 1)));
+
+    let input = Day06::parse(&read_input("data/2024/06_sample.txt")).unwrap();
+    let answer: Answer = Day06::p1(&input, &Params::default()).into();
+    assert_eq!(answer, Answer::Num(41));
+    assert_eq!(Day06::parse("..\n..").err().unwrap().expected, "a guard '^'");
+
+    let day = aoc24::find_day(2024, 1).unwrap();
+    let solver = (day.parse)(&read_input("data/2024/01_sample.txt")).unwrap();
+    assert_eq!(solver.solve(1, &Params::new(day.params)).to_string(), "11");
+    let err = (aoc24::find_day(2024, 13).unwrap().parse)("Button A: X+1")
+        .err()
+        .unwrap();
+    assert_eq!(err.expected, "', '");
+    let err = (day.parse)("3   4\n4   x").err().unwrap();
+    assert_eq!((err.found.as_str(), err.expected.as_str()), ("x", "an integer"));
+}
@@ -0,0 +1,515 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc24::prop::{self, check_cases, grid_to_string, shrink_grid, shrink_vec, Rng};
+use aoc24::solution::{Answer, Day, Params, Solution};
+use aoc24::y2024::{day06::Day06, day10::Day10, day12::Day12, day14::Day14, day17::Day17, day24::Day24};
+
+// each day: random valid inputs, and its parts checked against a slow and obvious reference.
+// `PROP_CASES=10000 cargo test --release --test props` searches longer, PROP_SEED replays a failure
+
+//--------------------------------------------------------------------------------
+// helpers
+//--------------------------------------------------------------------------------
+
+fn solve<S: Solution + 'static>(input: &str, part: u8, params: &[(&str, String)]) -> Answer {
+    let day = Day::of::<S>();
+    let mut declared = Params::new(day.params);
+    for (name, value) in params {
+        declared.set(name, value).unwrap();
+    }
+    let solver = (day.parse)(input).map_err(|err| err.to_string()).unwrap();
+    solver.solve(part, &declared)
+}
+
+fn compare(part: u8, got: Answer, expected: impl Into<Answer>) -> Result<(), String> {
+    let expected = expected.into();
+    match got == expected {
+        true => Ok(()),
+        false => Err(format!("p{}: got {}, expected {}", part, got, expected)),
+    }
+}
+
+fn run<T: Clone>(
+    max_size: usize,
+    gen: impl Fn(&mut Rng, usize) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    check: impl Fn(&T) -> Result<(), String>,
+    show: impl Fn(&T) -> String,
+) {
+    let (seed, nb_cases) = prop::config();
+    if let Err(failure) = check_cases(seed, nb_cases, max_size, gen, shrink, check) {
+        panic!("{}", failure.pp(show));
+    }
+}
+
+type Grid = Vec<Vec<char>>;
+
+fn random_grid(rng: &mut Rng, size: usize, cell: impl Fn(&mut Rng) -> char) -> Grid {
+    let (width, height) = (rng.range(1, size as i64) as usize, rng.range(1, size as i64) as usize);
+    (0..height).map(|_| (0..width).map(|_| cell(rng)).collect()).collect()
+}
+
+const DIRS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+fn at(grid: &Grid, x: i64, y: i64) -> Option<char> {
+    grid.get(usize::try_from(y).ok()?)?
+        .get(usize::try_from(x).ok()?)
+        .copied()
+}
+
+//--------------------------------------------------------------------------------
+// day06: guard walking in a grid of obstacles
+//--------------------------------------------------------------------------------
+
+// the cells the guard visits, or None if it walks in a loop
+fn walk_06(grid: &Grid) -> Option<HashSet<(i64, i64)>> {
+    let (mut x, mut y) = (0..grid.len())
+        .flat_map(|y| (0..grid[y].len()).map(move |x| (x as i64, y as i64)))
+        .find(|&(x, y)| at(grid, x, y) == Some('^'))
+        .unwrap();
+    let mut dir = 0;
+    let mut states = HashSet::new();
+    while states.insert((x, y, dir)) {
+        let (nx, ny) = (x + DIRS[dir].0, y + DIRS[dir].1);
+        match at(grid, nx, ny) {
+            None => return Some(states.iter().map(|&(x, y, _)| (x, y)).collect()),
+            Some('#') => dir = (dir + 1) % 4,
+            _ => (x, y) = (nx, ny),
+        }
+    }
+    None
+}
+
+// p2: try an obstacle on every free cell
+fn p2_06(grid: &Grid) -> usize {
+    let mut nb_loops = 0;
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if grid[y][x] == '.' {
+                let mut with_obstacle = grid.clone();
+                with_obstacle[y][x] = '#';
+                nb_loops += walk_06(&with_obstacle).is_none() as usize;
+            }
+        }
+    }
+    nb_loops
+}
+
+#[test]
+fn day06() {
+    let gen = |rng: &mut Rng, size: usize| {
+        let mut grid = random_grid(rng, size + 2, |rng| if rng.chance(0.2) { '#' } else { '.' });
+        let y = rng.below(grid.len() as u64) as usize;
+        let x = rng.below(grid[0].len() as u64) as usize;
+        grid[y][x] = '^';
+        grid
+    };
+    // the guard can't walk in a loop without an obstacle we add
+    let valid = |grid: &Grid| grid.iter().flatten().filter(|&&c| c == '^').count() == 1 && walk_06(grid).is_some();
+    let shrink = |grid: &Grid| shrink_grid(grid, '.').into_iter().filter(valid).collect();
+    let check = |grid: &Grid| {
+        if !valid(grid) {
+            return Ok(());
+        }
+        let input = grid_to_string(grid);
+        compare(1, solve::<Day06>(&input, 1, &[]), walk_06(grid).unwrap().len())?;
+        compare(2, solve::<Day06>(&input, 2, &[]), p2_06(grid))
+    };
+    run(12, gen, shrink, check, |grid| grid_to_string(grid));
+}
+
+//--------------------------------------------------------------------------------
+// day10: hiking trails in a height map
+//--------------------------------------------------------------------------------
+
+// every trail from (x, y) to a 9, as the list of the 9s it ends on
+fn trails_10(grid: &Grid, x: i64, y: i64, ends: &mut Vec<(i64, i64)>) {
+    let height = at(grid, x, y).and_then(|c| c.to_digit(10)).unwrap();
+    if height == 9 {
+        ends.push((x, y));
+        return;
+    }
+    for (dx, dy) in DIRS {
+        if at(grid, x + dx, y + dy).and_then(|c| c.to_digit(10)) == Some(height + 1) {
+            trails_10(grid, x + dx, y + dy, ends);
+        }
+    }
+}
+
+fn reference_10(grid: &Grid) -> (usize, usize) {
+    let (mut score, mut rating) = (0, 0);
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if grid[y][x] == '0' {
+                let mut ends = vec![];
+                trails_10(grid, x as i64, y as i64, &mut ends);
+                rating += ends.len();
+                score += ends.iter().collect::<HashSet<_>>().len();
+            }
+        }
+    }
+    (score, rating)
+}
+
+#[test]
+fn day10() {
+    // random heights have almost no trail: carve a few, each one a walk from 0 to 9
+    let gen = |rng: &mut Rng, size: usize| {
+        let mut grid = random_grid(rng, size + 2, |rng| match rng.chance(0.1) {
+            true => '.',
+            false => char::from_digit(rng.below(10) as u32, 10).unwrap(),
+        });
+        for _ in 0..rng.range(0, 4) {
+            let (mut x, mut y) = (
+                rng.below(grid[0].len() as u64) as i64,
+                rng.below(grid.len() as u64) as i64,
+            );
+            for height in 0..10 {
+                grid[y as usize][x as usize] = char::from_digit(height, 10).unwrap();
+                let (dx, dy) = *rng.choose(&DIRS);
+                if at(&grid, x + dx, y + dy).is_some() {
+                    (x, y) = (x + dx, y + dy);
+                }
+            }
+        }
+        grid
+    };
+    let check = |grid: &Grid| {
+        let input = grid_to_string(grid);
+        let (score, rating) = reference_10(grid);
+        compare(1, solve::<Day10>(&input, 1, &[]), score)?;
+        compare(2, solve::<Day10>(&input, 2, &[]), rating)
+    };
+    run(
+        10,
+        gen,
+        |grid| shrink_grid(grid, '.'),
+        check,
+        |grid| grid_to_string(grid),
+    );
+}
+
+//--------------------------------------------------------------------------------
+// day12: fences around garden regions
+//--------------------------------------------------------------------------------
+
+// area, perimeter and number of sides (= number of corners) of each region
+fn reference_12(grid: &Grid) -> (usize, usize) {
+    let same = |x: i64, y: i64, c: char| at(grid, x, y) == Some(c);
+    let mut seen = HashSet::new();
+    let (mut by_perimeter, mut by_sides) = (0, 0);
+    for y in 0..grid.len() as i64 {
+        for x in 0..grid[y as usize].len() as i64 {
+            if !seen.insert((x, y)) {
+                continue;
+            }
+            let c = grid[y as usize][x as usize];
+            let (mut area, mut perimeter, mut corners) = (0, 0, 0);
+            let mut queue = VecDeque::from([(x, y)]);
+            while let Some((x, y)) = queue.pop_front() {
+                area += 1;
+                for (i, (dx, dy)) in DIRS.into_iter().enumerate() {
+                    if same(x + dx, y + dy, c) {
+                        if seen.insert((x + dx, y + dy)) {
+                            queue.push_back((x + dx, y + dy));
+                        }
+                    } else {
+                        perimeter += 1;
+                    }
+                    // the corner between this direction and the next one
+                    let (ex, ey) = DIRS[(i + 1) % 4];
+                    let (side1, side2) = (same(x + dx, y + dy, c), same(x + ex, y + ey, c));
+                    let diagonal = same(x + dx + ex, y + dy + ey, c);
+                    if (!side1 && !side2) || (side1 && side2 && !diagonal) {
+                        corners += 1;
+                    }
+                }
+            }
+            by_perimeter += area * perimeter;
+            by_sides += area * corners;
+        }
+    }
+    (by_perimeter, by_sides)
+}
+
+#[test]
+fn day12() {
+    let gen = |rng: &mut Rng, size: usize| {
+        let nb_plants = rng.range(1, 4) as u8;
+        random_grid(rng, size + 2, |rng| (b'A' + rng.below(nb_plants as u64) as u8) as char)
+    };
+    let check = |grid: &Grid| {
+        let input = grid_to_string(grid);
+        let (by_perimeter, by_sides) = reference_12(grid);
+        compare(1, solve::<Day12>(&input, 1, &[]), by_perimeter)?;
+        compare(2, solve::<Day12>(&input, 2, &[]), by_sides)
+    };
+    run(
+        10,
+        gen,
+        |grid| shrink_grid(grid, 'A'),
+        check,
+        |grid| grid_to_string(grid),
+    );
+}
+
+//--------------------------------------------------------------------------------
+// day14: robots wrapping around an area
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct Robots {
+    width: i64,
+    height: i64,
+    robots: Vec<(i64, i64, i64, i64)>, // px, py, vx, vy
+}
+
+impl Robots {
+    fn input(&self) -> String {
+        let lines = self
+            .robots
+            .iter()
+            .map(|(px, py, vx, vy)| format!("p={},{} v={},{}", px, py, vx, vy));
+        lines.collect::<Vec<_>>().join("\n")
+    }
+}
+
+// p1: where they are after 100 seconds, in one jump
+fn reference_14(robots: &Robots) -> usize {
+    let mut quadrants = [0; 4];
+    for &(px, py, vx, vy) in &robots.robots {
+        let x = (px + 100 * vx).rem_euclid(robots.width);
+        let y = (py + 100 * vy).rem_euclid(robots.height);
+        let (mid_x, mid_y) = (robots.width / 2, robots.height / 2);
+        if x != mid_x && y != mid_y {
+            quadrants[(x > mid_x) as usize + 2 * (y > mid_y) as usize] += 1;
+        }
+    }
+    quadrants.iter().product()
+}
+
+#[test]
+fn day14() {
+    // odd sizes, like the puzzle: the middle row and column are well defined
+    let gen = |rng: &mut Rng, size: usize| {
+        let width = 2 * rng.range(1, size as i64) + 1;
+        let height = 2 * rng.range(1, size as i64) + 1;
+        let robots = (0..rng.range(0, 4 * size as i64))
+            .map(|_| {
+                let (px, py) = (rng.range(0, width - 1), rng.range(0, height - 1));
+                (px, py, rng.range(-width, width), rng.range(-height, height))
+            })
+            .collect();
+        Robots { width, height, robots }
+    };
+    let shrink = |robots: &Robots| {
+        shrink_vec(&robots.robots)
+            .into_iter()
+            .map(|smaller| Robots {
+                robots: smaller,
+                ..robots.clone()
+            })
+            .collect()
+    };
+    let check = |robots: &Robots| {
+        let params = [
+            ("width", robots.width.to_string()),
+            ("height", robots.height.to_string()),
+        ];
+        compare(1, solve::<Day14>(&robots.input(), 1, &params), reference_14(robots))
+    };
+    let show = |robots: &Robots| format!("{}x{}\n{}", robots.width, robots.height, robots.input());
+    run(8, gen, shrink, check, show);
+}
+
+//--------------------------------------------------------------------------------
+// day17: programs of the 3-bit computer
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct Program {
+    registers: [u64; 3],
+    body: Vec<(u64, u64)>, // no jump: the loop is added at the end
+}
+
+impl Program {
+    // the body, then `adv 3` and `jnz 0`: A only ever shrinks, so every program stops
+    fn code(&self) -> Vec<u64> {
+        let body = self.body.iter().flat_map(|&(op, operand)| [op, operand]);
+        body.chain([0, 3, 3, 0]).collect()
+    }
+
+    fn input(&self) -> String {
+        let [a, b, c] = self.registers;
+        let code = self.code().iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        format!(
+            "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}",
+            a,
+            b,
+            c,
+            code.join(",")
+        )
+    }
+}
+
+// a division by 2^n, as the puzzle says (a shift by 64 or more is 0)
+fn reference_17(program: &Program) -> String {
+    let code = program.code();
+    let [mut a, mut b, mut c] = program.registers;
+    let div = |a: u64, n: u64| if n >= 64 { 0 } else { a / (1 << n) };
+    let mut out = vec![];
+    let mut pc = 0;
+    while pc + 1 < code.len() {
+        let (op, operand) = (code[pc], code[pc + 1]);
+        let combo = [0, 1, 2, 3, a, b, c][operand as usize % 7];
+        pc += 2;
+        match op {
+            0 => a = div(a, combo),
+            1 => b ^= operand,
+            2 => b = combo % 8,
+            3 if a != 0 => pc = operand as usize,
+            3 => {}
+            4 => b ^= c,
+            5 => out.push((combo % 8).to_string()),
+            6 => b = div(a, combo),
+            _ => c = div(a, combo),
+        }
+    }
+    out.join(",")
+}
+
+#[test]
+fn day17() {
+    let gen = |rng: &mut Rng, size: usize| Program {
+        registers: [rng.below(1 << (3 * size)), rng.below(64), rng.below(64)],
+        body: (0..rng.range(1, size as i64))
+            .map(|_| (*rng.choose(&[0, 1, 2, 4, 5, 6, 7]), rng.below(7)))
+            .collect(),
+    };
+    let shrink = |program: &Program| {
+        let mut res = shrink_vec(&program.body)
+            .into_iter()
+            .map(|body| Program {
+                body,
+                ..program.clone()
+            })
+            .collect::<Vec<_>>();
+        for i in 0..3 {
+            if program.registers[i] > 0 {
+                let mut registers = program.registers;
+                registers[i] /= 2;
+                res.push(Program {
+                    registers,
+                    ..program.clone()
+                });
+            }
+        }
+        res
+    };
+    let check = |program: &Program| compare(1, solve::<Day17>(&program.input(), 1, &[]), reference_17(program));
+    run(8, gen, shrink, check, |program| program.input());
+}
+
+//--------------------------------------------------------------------------------
+// day24: netlists of logic gates
+//--------------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct Netlist {
+    inputs: Vec<(String, bool)>,
+    gates: Vec<(String, &'static str, String, String)>, // in1, op, in2, out
+}
+
+impl Netlist {
+    fn input(&self) -> String {
+        let inputs = self.inputs.iter().map(|(w, v)| format!("{}: {}", w, *v as u8));
+        let gates = self
+            .gates
+            .iter()
+            .map(|(a, op, b, out)| format!("{} {} {} -> {}", a, op, b, out));
+        format!(
+            "{}\n\n{}",
+            inputs.collect::<Vec<_>>().join("\n"),
+            gates.collect::<Vec<_>>().join("\n")
+        )
+    }
+}
+
+// p1: evaluate the gates in passes until every one has its inputs, then read the z wires
+fn reference_24(netlist: &Netlist) -> u64 {
+    let mut wires: HashMap<&str, bool> = netlist.inputs.iter().map(|(w, v)| (w.as_str(), *v)).collect();
+    while wires.len() < netlist.inputs.len() + netlist.gates.len() {
+        for (a, op, b, out) in &netlist.gates {
+            if let (Some(&a), Some(&b)) = (wires.get(a.as_str()), wires.get(b.as_str())) {
+                let v = match *op {
+                    "AND" => a && b,
+                    "OR" => a || b,
+                    _ => a ^ b,
+                };
+                wires.insert(out, v);
+            }
+        }
+    }
+    wires
+        .iter()
+        .filter_map(|(w, &v)| Some((w.strip_prefix('z')?.parse::<u32>().ok()?, v)))
+        .map(|(bit, v)| (v as u64) << bit)
+        .sum()
+}
+
+#[test]
+fn day24() {
+    // x and y bits, gates in order (so it's a dag), the last ones go to z00, z01... then the
+    // lines are shuffled
+    let gen = |rng: &mut Rng, size: usize| {
+        let nb_bits = rng.range(1, 1 + size as i64 / 2);
+        let inputs = ["x", "y"]
+            .iter()
+            .flat_map(|var| (0..nb_bits).map(move |i| format!("{}{:02}", var, i)))
+            .map(|w| (w, rng.chance(0.5)))
+            .collect::<Vec<_>>();
+        let nb_gates = rng.range(1, 2 + 2 * size as i64) as usize;
+        let nb_z = rng.range(1, nb_gates.min(8) as i64) as usize;
+        let mut wires = inputs.iter().map(|(w, _)| w.clone()).collect::<Vec<_>>();
+        let mut gates = vec![];
+        for i in 0..nb_gates {
+            let out = match i + nb_z >= nb_gates {
+                true => format!("z{:02}", i + nb_z - nb_gates),
+                false => format!("g{:02}", i),
+            };
+            let (a, b) = (rng.choose(&wires).clone(), rng.choose(&wires).clone());
+            gates.push((a, *rng.choose(&["AND", "OR", "XOR"]), b, out.clone()));
+            wires.push(out);
+        }
+        for i in (1..gates.len()).rev() {
+            gates.swap(i, rng.below(i as u64 + 1) as usize);
+        }
+        Netlist { inputs, gates }
+    };
+    // only gates that feed nothing and don't give a z bit can go
+    let shrink = |netlist: &Netlist| {
+        let used = netlist
+            .gates
+            .iter()
+            .flat_map(|(a, _, b, _)| [a.clone(), b.clone()])
+            .collect::<HashSet<_>>();
+        let mut res = vec![];
+        for (i, (_, _, _, out)) in netlist.gates.iter().enumerate() {
+            if !used.contains(out) && !out.starts_with('z') {
+                let mut smaller = netlist.clone();
+                smaller.gates.remove(i);
+                res.push(smaller);
+            }
+        }
+        for (i, (_, v)) in netlist.inputs.iter().enumerate() {
+            if *v {
+                let mut simpler = netlist.clone();
+                simpler.inputs[i].1 = false;
+                res.push(simpler);
+            }
+        }
+        res
+    };
+    let check = |netlist: &Netlist| compare(1, solve::<Day24>(&netlist.input(), 1, &[]), reference_24(netlist));
+    run(10, gen, shrink, check, |netlist| netlist.input());
+}